@@ -0,0 +1,152 @@
+pub mod clock {
+    use chrono::{DateTime, Duration, Local};
+    use std::sync::{Arc, Mutex};
+    use std::time::{self, Instant};
+
+    /// Intervalle minimal entre deux vérifications des alarmes, en secondes réelles.
+    const MIN_CHECK_INTERVAL_SECS: f64 = 0.01;
+
+    /// Source de l'heure courante, remplaçable pour simuler le temps.
+    pub trait Clock: Send + Sync {
+        /// Retourne l'instant présent.
+        fn now(&self) -> DateTime<Local>;
+
+        /// Retourne le nombre de secondes écoulées sur l'horloge par seconde réelle.
+        fn speed(&self) -> f64 {
+            1.0
+        }
+
+        /// Indique si l'heure est simulée ; l'historique n'est alors pas sauvegardé.
+        fn is_simulated(&self) -> bool {
+            false
+        }
+
+        /// Retourne l'intervalle réel entre deux vérifications des alarmes : une par seconde de
+        /// l'horloge, mais pas plus d'une par `MIN_CHECK_INTERVAL_SECS`.
+        fn check_interval(&self) -> time::Duration {
+            time::Duration::from_secs_f64((1.0 / self.speed()).max(MIN_CHECK_INTERVAL_SECS))
+        }
+    }
+
+    /// Horloge du système.
+    pub struct SystemClock;
+
+    impl Clock for SystemClock {
+        fn now(&self) -> DateTime<Local> {
+            Local::now()
+        }
+    }
+
+    /// Horloge simulée, avancée à la main ; les copies partagent le même instant.
+    #[derive(Clone)]
+    pub struct FakeClock {
+        now: Arc<Mutex<DateTime<Local>>>,
+    }
+
+    impl FakeClock {
+        /// Crée une nouvelle instance de `FakeClock`.
+        ///
+        /// # Parameters
+        ///
+        /// * `start`: L'instant de départ.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `FakeClock` arrêtée sur `start`.
+        pub fn new(start: DateTime<Local>) -> Self {
+            FakeClock {
+                now: Arc::new(Mutex::new(start)),
+            }
+        }
+
+        /// Place l'horloge sur l'instant donné.
+        ///
+        /// # Parameters
+        ///
+        /// * `at`: Le nouvel instant.
+        pub fn set(&self, at: DateTime<Local>) {
+            *self.now.lock().unwrap() = at;
+        }
+
+        /// Avance l'horloge.
+        ///
+        /// # Parameters
+        ///
+        /// * `duration`: La durée dont avancer.
+        pub fn advance(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Local> {
+            *self.now.lock().unwrap()
+        }
+
+        fn is_simulated(&self) -> bool {
+            true
+        }
+    }
+
+    /// Horloge virtuelle : part d'un instant choisi et avance `speed` fois plus vite que le temps réel.
+    pub struct SimulatedClock {
+        start: DateTime<Local>,
+        origin: Instant,
+        speed: f64,
+    }
+
+    impl SimulatedClock {
+        /// Crée une nouvelle instance de `SimulatedClock`.
+        ///
+        /// # Parameters
+        ///
+        /// * `start`: L'instant virtuel de départ.
+        /// * `speed`: Le nombre de secondes virtuelles par seconde réelle.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `SimulatedClock`, qui part de `start` dès maintenant.
+        pub fn new(start: DateTime<Local>, speed: f64) -> Self {
+            SimulatedClock {
+                start,
+                origin: Instant::now(),
+                speed,
+            }
+        }
+    }
+
+    impl Clock for SimulatedClock {
+        fn now(&self) -> DateTime<Local> {
+            let elapsed = self.origin.elapsed().mul_f64(self.speed);
+            self.start + Duration::from_std(elapsed).unwrap_or_else(|_| Duration::zero())
+        }
+
+        fn speed(&self) -> f64 {
+            self.speed
+        }
+
+        fn is_simulated(&self) -> bool {
+            true
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use chrono::TimeZone;
+        use std::thread;
+
+        #[test]
+        fn simulated_clock_runs_faster_than_real_time() {
+            let start = Local.with_ymd_and_hms(2026, 10, 19, 6, 0, 0).unwrap();
+            let clock = SimulatedClock::new(start, 60.0);
+            thread::sleep(time::Duration::from_millis(100));
+
+            // 100 ms réelles valent au moins 6 secondes virtuelles.
+            assert!(clock.now() - start >= Duration::seconds(6));
+            assert!(clock.now() - start < Duration::minutes(10));
+            assert_eq!(clock.check_interval(), time::Duration::from_secs_f64(1.0 / 60.0));
+            assert_eq!(SystemClock.check_interval(), time::Duration::from_secs(1));
+        }
+    }
+}
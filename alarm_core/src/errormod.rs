@@ -0,0 +1,55 @@
+pub mod error {
+    use std::fmt;
+    use std::io;
+
+    /// Erreur de l'application.
+    ///
+    /// Aucune de ces erreurs n'arrête l'application : elles sont journalisées et affichées,
+    /// et le planificateur continue de faire sonner les alarmes suivantes.
+    #[derive(Debug)]
+    pub enum Error {
+        /// La chanson d'une alarme n'a pas pu être téléchargée.
+        Download(String),
+        /// Une musique ou une radio n'a pas pu être lue.
+        Playback(String),
+        /// Un fichier de sauvegarde (alarmes, historique) n'a pas pu être lu ou écrit.
+        Persistence(io::Error),
+        /// La configuration ou une ressource de l'interface est invalide.
+        Config(String),
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Error::Download(message) => write!(f, "Téléchargement impossible : {}", message),
+                Error::Playback(message) => write!(f, "Lecture impossible : {}", message),
+                Error::Persistence(error) => write!(f, "Sauvegarde impossible : {}", error),
+                Error::Config(message) => write!(f, "Configuration invalide : {}", message),
+            }
+        }
+    }
+
+    impl std::error::Error for Error {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                Error::Persistence(error) => Some(error),
+                _ => None,
+            }
+        }
+    }
+
+    impl From<io::Error> for Error {
+        fn from(error: io::Error) -> Self {
+            Error::Persistence(error)
+        }
+    }
+
+    impl From<serde_json::Error> for Error {
+        fn from(error: serde_json::Error) -> Self {
+            Error::Persistence(error.into())
+        }
+    }
+
+    /// Résultat d'une opération pouvant échouer avec une `Error`.
+    pub type Result<T> = std::result::Result<T, Error>;
+}
@@ -0,0 +1,299 @@
+pub mod history {
+    use chrono::{DateTime, Datelike, Local};
+    use serde::{Serialize, Deserialize};
+    use std::collections::BTreeMap;
+    use std::fs::File;
+    use std::io::{self, Read, Write};
+    use tracing::{error, info};
+    use crate::errormod::error::Result;
+    use crate::modelmod::model::{AlarmClock, DAY_NAMES};
+
+    const HISTORY_PATH: &str = "ser/history.json";
+
+    /// Type d'événement enregistré dans l'historique des alarmes.
+    #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum EventKind {
+        Triggered,
+        Snoozed,
+        Dismissed,
+        Missed,
+        PlaybackFailed,
+        /// La sonnerie s'est arrêtée d'elle-même, sans action de l'utilisateur.
+        TimedOut,
+    }
+
+    impl EventKind {
+        /// Retourne le libellé de l'événement utilisé dans les exports.
+        pub fn label(&self) -> &'static str {
+            match self {
+                EventKind::Triggered => "triggered",
+                EventKind::Snoozed => "snoozed",
+                EventKind::Dismissed => "dismissed",
+                EventKind::Missed => "missed",
+                EventKind::PlaybackFailed => "failed",
+                EventKind::TimedOut => "timed_out",
+            }
+        }
+    }
+
+    /// Entrée de l'historique : un événement survenu sur une alarme.
+    #[derive(Clone, Serialize, Deserialize)]
+    pub struct HistoryEntry {
+        pub alarm_id: usize,
+        pub alarm_name: String,
+        pub kind: EventKind,
+        pub source: String,
+        pub scheduled: String,
+        pub timestamp: DateTime<Local>,
+        pub rang_for: Option<i64>,
+    }
+
+    impl HistoryEntry {
+        /// Crée une nouvelle entrée d'historique.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarm`: L'alarme concernée.
+        /// * `kind`: Le type d'événement.
+        /// * `rang_for`: La durée de sonnerie en secondes, si elle est connue.
+        /// * `timestamp`: L'instant de l'événement.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `HistoryEntry`.
+        pub fn new(alarm: &AlarmClock, kind: EventKind, rang_for: Option<i64>, timestamp: DateTime<Local>) -> Self {
+            HistoryEntry {
+                alarm_id: alarm.a_id,
+                alarm_name: alarm.name.clone(),
+                kind,
+                source: alarm.source_label(),
+                scheduled: alarm.scheduled_label(),
+                timestamp,
+                rang_for,
+            }
+        }
+    }
+
+    /// Alarme en train de sonner, conservée jusqu'à son arrêt ou son report.
+    #[derive(Clone)]
+    pub struct Ringing {
+        pub alarm: AlarmClock,
+        pub started: DateTime<Local>,
+    }
+
+    impl Ringing {
+        /// Crée une nouvelle instance de `Ringing`.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarm`: L'alarme qui sonne.
+        /// * `started`: L'instant du déclenchement.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `Ringing`.
+        pub fn new(alarm: AlarmClock, started: DateTime<Local>) -> Self {
+            Ringing {
+                alarm,
+                started,
+            }
+        }
+
+        /// Construit l'entrée d'historique clôturant la sonnerie.
+        ///
+        /// # Parameters
+        ///
+        /// * `kind`: `Snoozed`, `Dismissed` ou `TimedOut`.
+        /// * `now`: L'instant de l'arrêt ou du report.
+        ///
+        /// # Returns
+        ///
+        /// L'entrée d'historique avec la durée de sonnerie.
+        pub fn finish(&self, kind: EventKind, now: DateTime<Local>) -> HistoryEntry {
+            let rang_for = (now - self.started).num_seconds();
+            HistoryEntry::new(&self.alarm, kind, Some(rang_for), now)
+        }
+    }
+
+    /// Journal des événements d'alarme.
+    ///
+    /// L'historique chargé par `load` est sauvegardé à chaque événement ; celui créé par
+    /// `Default` reste en mémoire (tests, simulations).
+    #[derive(Clone, Default, Serialize, Deserialize)]
+    pub struct History {
+        pub entries: Vec<HistoryEntry>,
+        #[serde(skip)]
+        persistent: bool,
+    }
+
+    impl History {
+        /// Crée un historique vide, sauvegardé dans le fichier de sauvegarde.
+        pub fn new() -> Self {
+            History {
+                entries: Vec::new(),
+                persistent: true,
+            }
+        }
+
+        /// Charge l'historique depuis le fichier de sauvegarde.
+        ///
+        /// # Returns
+        ///
+        /// `Result<History>` - L'historique chargé, vide si le fichier n'existe pas encore ; une erreur
+        /// s'il ne peut pas être lu, afin de ne pas l'écraser au prochain événement.
+        pub fn load() -> Result<Self> {
+            let mut file = match File::open(HISTORY_PATH) {
+                Ok(file) => file,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(History::new()),
+                Err(error) => return Err(error.into()),
+            };
+            let mut contents = String::new();
+            file.read_to_string(&mut contents)?;
+            if contents.is_empty() {
+                return Ok(History::new());
+            }
+            let entries: Vec<HistoryEntry> = serde_json::from_str(&contents)?;
+            Ok(History { entries, persistent: true })
+        }
+
+        /// Sauvegarde l'historique dans le fichier de sauvegarde.
+        ///
+        /// # Returns
+        ///
+        /// `Result<()>` - Résultat de l'opération de sauvegarde.
+        pub fn save(&self) -> Result<()> {
+            let serialized = serde_json::to_string(&self.entries)?;
+            let mut file = File::create(HISTORY_PATH)?;
+            file.write_all(serialized.as_bytes())?;
+            Ok(())
+        }
+
+        /// Ajoute un événement à l'historique et le sauvegarde s'il est persistant.
+        ///
+        /// # Parameters
+        ///
+        /// * `entry`: L'événement à enregistrer.
+        pub fn record(&mut self, entry: HistoryEntry) {
+            info!(alarm_id = entry.alarm_id, "Alarm {}", entry.kind.label());
+            self.entries.push(entry);
+            if !self.persistent {
+                return;
+            }
+            if let Err(error) = self.save() {
+                error!(?error, "Failed to save history");
+            }
+        }
+    }
+
+    /// Statistiques de réveil calculées à partir de l'historique.
+    #[derive(Clone, Default, Serialize)]
+    pub struct Statistics {
+        pub triggered: u32,
+        pub missed: u32,
+        /// Sonneries arrêtées d'elles-mêmes ; elles ne comptent pas dans le temps moyen avant arrêt.
+        pub timed_out: u32,
+        pub average_dismiss_secs: Option<f64>,
+        pub snoozes_per_weekday: [u32; 7],
+        pub failures_per_station: BTreeMap<String, u32>,
+    }
+
+    impl Statistics {
+        /// Calcule les statistiques d'un historique.
+        ///
+        /// # Parameters
+        ///
+        /// * `history`: L'historique à analyser.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `Statistics`.
+        pub fn from_history(history: &History) -> Self {
+            let mut stats = Statistics::default();
+            let mut dismiss_total: i64 = 0;
+            let mut dismiss_count: u32 = 0;
+            for entry in history.entries.iter() {
+                match entry.kind {
+                    EventKind::Triggered => stats.triggered += 1,
+                    EventKind::Missed => stats.missed += 1,
+                    EventKind::TimedOut => stats.timed_out += 1,
+                    EventKind::Snoozed => {
+                        let day = entry.timestamp.weekday().num_days_from_monday() as usize;
+                        stats.snoozes_per_weekday[day] += 1;
+                    }
+                    EventKind::Dismissed => {
+                        if let Some(rang_for) = entry.rang_for {
+                            dismiss_total += rang_for;
+                            dismiss_count += 1;
+                        }
+                    }
+                    EventKind::PlaybackFailed => {
+                        *stats.failures_per_station.entry(entry.source.clone()).or_insert(0) += 1;
+                    }
+                }
+            }
+            if dismiss_count > 0 {
+                stats.average_dismiss_secs = Some(dismiss_total as f64 / dismiss_count as f64);
+            }
+            stats
+        }
+
+        /// Exporte les statistiques au format CSV (`metric,key,value`).
+        ///
+        /// # Returns
+        ///
+        /// Le contenu CSV.
+        pub fn to_csv(&self) -> String {
+            let mut csv = String::from("metric,key,value\n");
+            csv.push_str(&format!("triggered,,{}\n", self.triggered));
+            csv.push_str(&format!("missed,,{}\n", self.missed));
+            csv.push_str(&format!("timed_out,,{}\n", self.timed_out));
+            if let Some(average) = self.average_dismiss_secs {
+                csv.push_str(&format!("average_dismiss_secs,,{:.1}\n", average));
+            }
+            for (i, day) in DAY_NAMES.iter().enumerate() {
+                csv.push_str(&format!("snoozes,{},{}\n", day, self.snoozes_per_weekday[i]));
+            }
+            for (station, count) in self.failures_per_station.iter() {
+                csv.push_str(&format!("failures,\"{}\",{}\n", station.replace('"', "\"\""), count));
+            }
+            csv
+        }
+
+        /// Exporte les statistiques au format JSON.
+        ///
+        /// # Returns
+        ///
+        /// `serde_json::Result<String>` - Le contenu JSON.
+        pub fn to_json(&self) -> serde_json::Result<String> {
+            serde_json::to_string_pretty(self)
+        }
+
+        /// Résumé lisible des statistiques, affiché dans l'interface.
+        ///
+        /// # Returns
+        ///
+        /// Le texte du résumé.
+        pub fn summary(&self) -> String {
+            let average = match self.average_dismiss_secs {
+                Some(average) => format!("{:.0} s", average),
+                None => "-".to_string(),
+            };
+            let snoozes: Vec<String> = DAY_NAMES.iter()
+                .zip(self.snoozes_per_weekday.iter())
+                .map(|(day, count)| format!("{} {}", day, count))
+                .collect();
+            let failures: Vec<String> = self.failures_per_station.iter()
+                .map(|(station, count)| format!("{} : {}", station, count))
+                .collect();
+            format!(
+                "Déclenchements : {}   Manquées : {}   Arrêts automatiques : {}\nTemps moyen avant arrêt : {}\nReports : {}\nÉchecs : {}",
+                self.triggered,
+                self.missed,
+                self.timed_out,
+                average,
+                snoozes.join("  "),
+                if failures.is_empty() { "-".to_string() } else { failures.join(", ") },
+            )
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Cœur du réveil, sans dépendance à GTK ni aux lecteurs audio.
+//!
+//! Il regroupe le modèle des alarmes et leur sauvegarde, l'historique, le planificateur,
+//! l'horloge injectable, l'horloge mondiale, les minuteurs, l'état partagé de l'application,
+//! ainsi que l'interface des lecteurs et `RecordingPlayer`, qui enregistre les lectures sans les jouer.
+//! L'interface graphique et le mode `--headless` en sont des clients.
+
+pub mod clockmod;
+pub mod errormod;
+pub mod historymod;
+pub mod modelmod;
+pub mod playermod;
+pub mod schedulermod;
+pub mod statemod;
+pub mod timermod;
+pub mod worldclockmod;
+#[cfg(test)]
+mod testmod;
@@ -0,0 +1,670 @@
+pub mod model {
+    use chrono::{DateTime, Datelike, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike};
+    use chrono_tz::Tz;
+    use serde::{Serialize, Deserialize};
+    use std::time::SystemTime;
+    use std::fs::{self, File};
+    use std::io::{self, Write};
+    use std::path::{Path, PathBuf};
+    use crate::errormod::error::Result;
+
+    const ALARMS_PATH: &str = "ser/alarms.json";
+
+    /// Fichier, à côté du fichier des alarmes, qui retient le prochain identifiant libre.
+    const NEXT_ID_FILE: &str = "next_alarm_id";
+
+    /// Abréviations françaises des jours, du lundi au dimanche.
+    pub const DAY_NAMES: [&str; 7] = ["Lun", "Mar", "Mer", "Jeu", "Ven", "Sam", "Dim"];
+
+    /// Charge les alarmes depuis le fichier de sauvegarde.
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<AlarmClock>>` - Les alarmes sauvegardées, vide si le fichier est vide ou absent.
+    pub fn load_alarms() -> Result<Vec<AlarmClock>> {
+        load_alarms_from(Path::new(ALARMS_PATH))
+    }
+
+    /// Charge les alarmes depuis un fichier donné.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Le chemin du fichier de sauvegarde.
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<AlarmClock>>` - Les alarmes sauvegardées, vide si le fichier est vide ou absent.
+    pub fn load_alarms_from(path: &Path) -> Result<Vec<AlarmClock>> {
+        match fs::read_to_string(path) {
+            Ok(contents) => parse_alarms(&contents),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Lit les alarmes depuis le contenu d'un fichier de sauvegarde.
+    ///
+    /// # Parameters
+    ///
+    /// * `contents`: Le contenu du fichier, au format JSON.
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<AlarmClock>>` - Les alarmes lues, vide si le contenu est vide ; une erreur s'il est corrompu.
+    pub fn parse_alarms(contents: &str) -> Result<Vec<AlarmClock>> {
+        if contents.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        let alarms: Vec<AlarmClock> = serde_json::from_str(contents)?;
+        Ok(alarms)
+    }
+
+    /// Sauvegarde les alarmes dans le fichier de sauvegarde.
+    ///
+    /// # Parameters
+    ///
+    /// * `alarms`: Les alarmes à sauvegarder.
+    ///
+    /// # Returns
+    ///
+    /// `Result<()>` - Résultat de l'opération de sauvegarde.
+    pub fn save_alarms(alarms: &[AlarmClock]) -> Result<()> {
+        save_alarms_to(Path::new(ALARMS_PATH), alarms)
+    }
+
+    /// Sauvegarde les alarmes dans un fichier donné.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Le chemin du fichier de sauvegarde.
+    /// * `alarms`: Les alarmes à sauvegarder.
+    ///
+    /// # Returns
+    ///
+    /// `Result<()>` - Résultat de l'opération de sauvegarde.
+    pub fn save_alarms_to(path: &Path, alarms: &[AlarmClock]) -> Result<()> {
+        let serialized = serde_json::to_string(alarms)?;
+        let mut file = File::create(path)?;
+        file.write_all(serialized.as_bytes())?;
+        // Le compteur survit à la suppression de l'alarme au plus grand identifiant.
+        let next_id = next_alarm_id_from(path, alarms);
+        fs::write(next_id_path(path), next_id.to_string())?;
+        Ok(())
+    }
+
+    /// Retourne un identifiant libre pour une nouvelle alarme.
+    ///
+    /// Les identifiants ne sont jamais réattribués : l'historique et les fichiers
+    /// `song/Alarm_<id>.wav` y font référence.
+    ///
+    /// # Parameters
+    ///
+    /// * `alarms`: Les alarmes existantes.
+    ///
+    /// # Returns
+    ///
+    /// Un identifiant jamais attribué par une sauvegarde précédente, ni par les alarmes existantes.
+    pub fn next_alarm_id(alarms: &[AlarmClock]) -> usize {
+        next_alarm_id_from(Path::new(ALARMS_PATH), alarms)
+    }
+
+    /// Retourne un identifiant libre pour une nouvelle alarme du fichier donné.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Le chemin du fichier de sauvegarde des alarmes ; le compteur est lu à côté.
+    /// * `alarms`: Les alarmes existantes.
+    ///
+    /// # Returns
+    ///
+    /// Le plus grand du compteur sauvegardé et du successeur des identifiants existants.
+    pub fn next_alarm_id_from(path: &Path, alarms: &[AlarmClock]) -> usize {
+        let saved = fs::read_to_string(next_id_path(path)).ok()
+            .and_then(|contents: String| contents.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+        alarms.iter().map(|alarm: &AlarmClock| alarm.a_id.saturating_add(1)).fold(saved, usize::max)
+    }
+
+    /// Retourne le chemin du compteur d'identifiants associé à un fichier d'alarmes.
+    fn next_id_path(path: &Path) -> PathBuf {
+        path.with_file_name(NEXT_ID_FILE)
+    }
+
+    /// Retourne la prochaine alarme active à sonner après l'instant donné.
+    ///
+    /// # Parameters
+    ///
+    /// * `alarms`: Les alarmes à examiner.
+    /// * `after`: L'instant à partir duquel chercher.
+    ///
+    /// # Returns
+    ///
+    /// L'alarme et la date de son prochain déclenchement, ou `None` si aucune alarme n'est programmée.
+    pub fn next_alarm(alarms: &[AlarmClock], after: DateTime<Local>) -> Option<(AlarmClock, DateTime<Local>)> {
+        alarms.iter()
+            .filter(|alarm: &&AlarmClock| alarm.active)
+            .filter_map(|alarm: &AlarmClock| alarm.next_occurrence(after).map(|at| (alarm.clone(), at)))
+            .min_by_key(|(_, at)| *at)
+    }
+
+    /// Désactive les alarmes à date unique qui ne sonneront plus.
+    ///
+    /// # Parameters
+    ///
+    /// * `alarms`: Les alarmes à vérifier.
+    /// * `now`: L'instant présent.
+    ///
+    /// # Returns
+    ///
+    /// `true` si au moins une alarme a été désactivée.
+    pub fn disable_expired(alarms: &mut [AlarmClock], now: DateTime<Local>) -> bool {
+        let mut disabled = false;
+        for alarm in alarms.iter_mut().filter(|alarm: &&mut AlarmClock| alarm.active && alarm.date.is_some()) {
+            if alarm.next_occurrence(now).is_none() {
+                alarm.active = false;
+                disabled = true;
+            }
+        }
+        disabled
+    }
+
+    /// Retourne la date de dernière modification du fichier de sauvegarde des alarmes.
+    ///
+    /// # Returns
+    ///
+    /// La date de modification, ou `None` si le fichier n'existe pas.
+    pub fn alarms_modified() -> Option<SystemTime> {
+        fs::metadata(ALARMS_PATH).and_then(|metadata| metadata.modified()).ok()
+    }
+
+    /// Analyse une heure au format `HH:MM` ou `HH:MM:SS`.
+    ///
+    /// # Returns
+    ///
+    /// Le triplet (heure, minutes, secondes).
+    pub fn parse_time(value: &str) -> std::result::Result<(u8, u8, u8), String> {
+        let parts: Vec<&str> = value.split(':').collect();
+        if parts.len() < 2 || parts.len() > 3 {
+            return Err(format!("Invalid time {}", value));
+        }
+        let mut fields = [0u8; 3];
+        for (i, part) in parts.iter().enumerate() {
+            fields[i] = part.parse().map_err(|_| format!("Invalid time {}", value))?;
+        }
+        if fields[0] > 23 || fields[1] > 59 || fields[2] > 59 {
+            return Err(format!("Invalid time {}", value));
+        }
+        Ok((fields[0], fields[1], fields[2]))
+    }
+
+    /// Retourne l'indice (0 pour lundi) d'un jour abrégé en anglais ou en français.
+    fn day_index(value: &str) -> Option<usize> {
+        let english = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+        let value = value.to_lowercase();
+        english.iter()
+            .position(|day: &&str| value.starts_with(day))
+            .or_else(|| DAY_NAMES.iter().position(|day: &&str| value.starts_with(&day.to_lowercase())))
+    }
+
+    /// Analyse une liste de jours (`mon-fri`, `sat,sun`, `lun,mer`, `all`).
+    ///
+    /// # Returns
+    ///
+    /// Les jours sélectionnés, du lundi au dimanche.
+    pub fn parse_days(value: &str) -> std::result::Result<[bool; 7], String> {
+        let mut days = [false; 7];
+        if value == "all" {
+            return Ok([true; 7]);
+        }
+        for part in value.split(',') {
+            let invalid = || format!("Invalid days {}", value);
+            match part.split_once('-') {
+                Some((from, to)) => {
+                    let from = day_index(from).ok_or_else(invalid)?;
+                    let to = day_index(to).ok_or_else(invalid)?;
+                    let mut day = from;
+                    loop {
+                        days[day] = true;
+                        if day == to {
+                            break;
+                        }
+                        day = (day + 1) % 7;
+                    }
+                }
+                None => days[day_index(part).ok_or_else(invalid)?] = true,
+            }
+        }
+        Ok(days)
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub enum RadioStation {
+        FranceInfo,
+        FranceInter,
+        RTL,
+        RireChanson,
+        Skyrock,
+    }
+
+    impl RadioStation {
+        /// Toutes les stations, dans l'ordre des boutons radio.
+        pub const ALL: [RadioStation; 5] = [
+            RadioStation::FranceInfo,
+            RadioStation::FranceInter,
+            RadioStation::RTL,
+            RadioStation::RireChanson,
+            RadioStation::Skyrock,
+        ];
+
+        /// Retrouve une station à partir de son nom, sans tenir compte de la casse ni des espaces.
+        ///
+        /// # Parameters
+        ///
+        /// * `name`: Le nom de la station (ex. "France Inter" ou "franceinter").
+        ///
+        /// # Returns
+        ///
+        /// La station correspondante, ou `None` si le nom est inconnu.
+        pub fn from_name(name: &str) -> Option<Self> {
+            let normalize = |value: &str| -> String {
+                value.chars().filter(|c: &char| c.is_alphanumeric()).collect::<String>().to_lowercase()
+            };
+            let wanted = normalize(name);
+            RadioStation::ALL.iter()
+                .find(|station: &&RadioStation| normalize(station.label()) == wanted || normalize(&format!("{:?}", station)) == wanted)
+                .cloned()
+        }
+
+        /// Retrouve une station à partir de l'URL de son flux.
+        ///
+        /// # Parameters
+        ///
+        /// * `url`: L'URL du flux.
+        ///
+        /// # Returns
+        ///
+        /// La station correspondante, ou `None` si l'URL est inconnue.
+        pub fn from_url(url: &str) -> Option<Self> {
+            RadioStation::ALL.iter().find(|station: &&RadioStation| station.url() == url).cloned()
+        }
+
+        /// Retourne le nom affiché de la station de radio.
+        ///
+        /// # Returns
+        ///
+        /// Le nom de la station, tel qu'affiché sur les boutons radio.
+        pub fn label(&self) -> &'static str {
+            match self {
+                RadioStation::FranceInfo => "France Info",
+                RadioStation::FranceInter => "France Inter",
+                RadioStation::RTL => "RTL",
+                RadioStation::RireChanson => "Rire & Chanson",
+                RadioStation::Skyrock => "Skyrock",
+            }
+        }
+
+        /// Retourne l'URL du flux de la station de radio.
+        ///
+        /// # Returns
+        ///
+        /// L'URL du flux audio de la station.
+        pub fn url(&self) -> &'static str {
+            match self {
+                RadioStation::FranceInfo => "http://direct.franceinfo.fr/live/franceinfo-midfi.mp3",
+                RadioStation::FranceInter => "http://direct.franceinter.fr/live/franceinter-midfi.mp3",
+                RadioStation::RTL => "http://streaming.radio.rtl.fr/rtl-1-44-128",
+                RadioStation::RireChanson => "http://cdn.nrjaudio.fm/audio1/fr/30401/mp3_128.mp3",
+                RadioStation::Skyrock => "http://icecast.skyrock.net/s/natio_mp3_128k",
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Horaire {
+        pub hour: u8,
+        pub minute: u8,
+        pub second: u8,
+    }
+
+    impl Horaire {
+        /// Crée une nouvelle instance de `Horaire` à l'heure de l'instant donné.
+        ///
+        /// # Parameters
+        ///
+        /// * `now`: L'instant présent, lu sur l'horloge de l'application.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `Horaire` avec l'heure actuelle.
+        pub fn new(now: DateTime<Local>) -> Self {
+            Self {
+                hour: now.hour() as u8,
+                minute: now.minute() as u8,
+                second: now.second() as u8,
+            }
+        }
+
+        /// Met à jour l'heure actuelle.
+        ///
+        /// # Parameters
+        ///
+        /// * `now`: L'instant présent, lu sur l'horloge de l'application.
+        pub fn update_time(&mut self, now: DateTime<Local>) {
+            self.hour = now.hour() as u8;
+            self.minute = now.minute() as u8;
+            self.second = now.second() as u8;
+        }
+
+        /// Retourne l'heure actuelle.
+        ///
+        /// # Returns
+        ///
+        /// L'heure actuelle.
+        pub fn get_hour(&self) -> u8 {
+            self.hour
+        }
+
+        /// Retourne les minutes actuelles.
+        ///
+        /// # Returns
+        ///
+        /// Les minutes actuelles.
+        pub fn get_min(&self) -> u8 {
+            self.minute
+        }
+
+        /// Retourne les secondes actuelles.
+        ///
+        /// # Returns
+        ///
+        /// Les secondes actuelles.
+        pub fn get_sec(&self) -> u8 {
+            self.second
+        }
+    }
+
+    /// Commandes utilisateur exécutées autour d'une alarme.
+    ///
+    /// Chaque commande est passée à `sh -c` ; une commande vide est ignorée.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct AlarmHooks {
+        /// Commande lancée `pre_alarm_minutes` minutes avant le déclenchement (ex. allumer le chauffage).
+        pub pre_alarm: String,
+        pub pre_alarm_minutes: u32,
+        /// Commande lancée au déclenchement de l'alarme.
+        pub on_fire: String,
+        /// Commande lancée à l'arrêt de l'alarme.
+        pub on_dismiss: String,
+    }
+
+    /// Comportement vis-à-vis des autres lecteurs multimédia (MPRIS) quand l'alarme sonne.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct OtherPlayers {
+        /// Met en pause les lecteurs en cours de lecture au déclenchement.
+        pub pause: bool,
+        /// Relance à l'arrêt de l'alarme les lecteurs mis en pause.
+        pub resume: bool,
+    }
+
+    impl Default for OtherPlayers {
+        fn default() -> Self {
+            OtherPlayers {
+                pause: true,
+                resume: false,
+            }
+        }
+    }
+
+    /// Heure d'alarme qui n'existe pas, sautée au passage à l'heure d'été (02:30 à Paris le dernier dimanche de mars).
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum NonexistentTime {
+        /// Sonne décalée de la durée du saut, comme l'heure d'hiver l'aurait donnée (02:30 devient 03:30).
+        #[default]
+        Shift,
+        /// Ne sonne pas ce jour-là.
+        Skip,
+    }
+
+    /// Heure d'alarme qui existe deux fois, au passage à l'heure d'hiver (02:30 à Paris le dernier dimanche d'octobre).
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum AmbiguousTime {
+        /// Sonne à la première des deux, à l'heure d'été.
+        #[default]
+        Earliest,
+        /// Sonne à la seconde, à l'heure d'hiver.
+        Latest,
+    }
+
+    /// Règles appliquées aux jours de changement d'heure ; l'alarme ne sonne jamais deux fois le même jour.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct DstPolicy {
+        pub nonexistent: NonexistentTime,
+        pub ambiguous: AmbiguousTime,
+    }
+
+    impl DstPolicy {
+        /// Convertit une heure locale d'un fuseau en instant, selon les règles de changement d'heure.
+        ///
+        /// # Parameters
+        ///
+        /// * `zone`: Le fuseau horaire de l'heure locale.
+        /// * `local`: La date et l'heure locales.
+        ///
+        /// # Returns
+        ///
+        /// L'instant correspondant, ou `None` si l'heure n'existe pas et doit être sautée.
+        pub fn resolve<Z: TimeZone>(&self, zone: &Z, local: NaiveDateTime) -> Option<DateTime<Z>> {
+            match zone.from_local_datetime(&local) {
+                LocalResult::Single(at) => Some(at),
+                LocalResult::Ambiguous(earliest, latest) => match self.ambiguous {
+                    AmbiguousTime::Earliest => Some(earliest),
+                    AmbiguousTime::Latest => Some(latest),
+                },
+                LocalResult::None => match self.nonexistent {
+                    NonexistentTime::Skip => None,
+                    NonexistentTime::Shift => {
+                        // Décalage en vigueur avant le saut : les sauts sont espacés de bien plus d'un jour.
+                        let before = zone.from_local_datetime(&(local - Duration::days(1))).earliest()?;
+                        let offset = before.offset().fix().local_minus_utc() as i64;
+                        Some(zone.from_utc_datetime(&(local - Duration::seconds(offset))))
+                    }
+                },
+            }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct AlarmClock {
+        pub horaire: Horaire,
+        pub active: bool,
+        pub is_radio: bool,
+        pub song_path: String,
+        pub song_title: String,
+        pub a_radio: Option<RadioStation>,
+        pub a_id: usize,
+        pub name: String,
+        pub days: [bool; 7],
+        #[serde(default)]
+        pub hooks: AlarmHooks,
+        #[serde(default)]
+        pub other_players: OtherPlayers,
+        /// Fuseau horaire IANA de l'heure de l'alarme (`Europe/Paris`) ; celui du système si absent.
+        #[serde(default)]
+        pub time_zone: Option<Tz>,
+        #[serde(default)]
+        pub dst: DstPolicy,
+        /// Date de l'alarme dans son fuseau horaire : elle ne sonne qu'une fois, ce jour-là, quels que soient
+        /// `days`, puis est désactivée. Alarme hebdomadaire si absente.
+        #[serde(default)]
+        pub date: Option<NaiveDate>,
+    }
+
+    impl AlarmClock {
+        /// Crée une nouvelle instance d'`AlarmClock`.
+        ///
+        /// # Parameters
+        ///
+        /// * `a_id`: L'identifiant de l'alarme.
+        /// * `name`: Le nom de l'alarme.
+        /// * `hour`: L'heure de l'alarme.
+        /// * `minute`: Les minutes de l'alarme.
+        /// * `second`: Les secondes de l'alarme.
+        /// * `is_radio`: Indique si c'est une radio.
+        /// * `a_radio`: L'option de station de radio.
+        /// * `days`: Les jours de l'alarme.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance d'`AlarmClock`, sans chanson : voir `download::get_song`.
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(a_id: usize, name: String, hour: u8, minute: u8, second: u8, is_radio: bool, a_radio: Option<RadioStation>, days: [bool; 7]) -> Self {
+            Self {
+                a_id,
+                name,
+                horaire: Horaire {
+                    hour,
+                    minute,
+                    second,
+                },
+                active: true,
+                is_radio,
+                song_path: String::new(),
+                song_title: String::new(),
+                a_radio,
+                days,
+                hooks: AlarmHooks::default(),
+                other_players: OtherPlayers::default(),
+                time_zone: None,
+                dst: DstPolicy::default(),
+                date: None,
+            }
+        }
+
+        /// Retourne l'heure de l'alarme, suivie de son fuseau horaire s'il est fixé.
+        ///
+        /// # Returns
+        ///
+        /// L'heure au format `HH:MM:SS`, par exemple `07:00:00 Europe/Paris`.
+        pub fn scheduled_label(&self) -> String {
+            let time = format!("{:02}:{:02}:{:02}", self.horaire.hour, self.horaire.minute, self.horaire.second);
+            match self.time_zone {
+                Some(zone) => format!("{} {}", time, zone.name()),
+                None => time,
+            }
+        }
+
+        /// Compare l'heure actuelle avec l'heure de l'alarme.
+        ///
+        /// La comparaison porte sur l'heure affichée, sans fuseau horaire ni changement d'heure :
+        /// le planificateur utilise `next_occurrence`.
+        ///
+        /// # Parameters
+        ///
+        /// * `other`: L'heure actuelle.
+        /// * `day_of_week`: Le jour de la semaine actuel.
+        ///
+        /// # Returns
+        ///
+        /// `true` si l'heure et le jour correspondent à l'alarme, `false` sinon.
+        pub fn to_compare(&self, other: &Horaire, day_of_week: usize) -> bool {
+            self.horaire.hour == other.hour &&
+            self.horaire.minute == other.minute &&
+            self.horaire.second == other.second &&
+            self.days[day_of_week]
+        }
+
+        /// Indique si la source audio de l'alarme peut être jouée.
+        ///
+        /// # Returns
+        ///
+        /// `true` si une station est sélectionnée ou si le fichier de la chanson existe.
+        pub fn is_playable(&self) -> bool {
+            if self.is_radio {
+                self.a_radio.is_some()
+            } else {
+                Path::new(&self.song_path).exists()
+            }
+        }
+
+        /// Retourne une description de la source audio de l'alarme.
+        ///
+        /// # Returns
+        ///
+        /// Le nom de la station de radio, ou le titre (à défaut le chemin) de la chanson.
+        pub fn source_label(&self) -> String {
+            if self.is_radio {
+                match &self.a_radio {
+                    Some(station) => station.label().to_string(),
+                    None => "Radio".to_string(),
+                }
+            } else if !self.song_title.is_empty() {
+                self.song_title.clone()
+            } else {
+                self.song_path.clone()
+            }
+        }
+
+        /// Calcule le prochain déclenchement de l'alarme strictement après l'instant donné.
+        ///
+        /// L'heure et les jours de l'alarme sont ceux de son fuseau horaire ; les jours de
+        /// changement d'heure suivent les règles `dst`.
+        ///
+        /// # Parameters
+        ///
+        /// * `after`: L'instant à partir duquel chercher.
+        ///
+        /// # Returns
+        ///
+        /// La date du prochain déclenchement, ou `None` si aucun jour n'est sélectionné
+        /// ou si la date d'une alarme unique est passée.
+        pub fn next_occurrence(&self, after: DateTime<Local>) -> Option<DateTime<Local>> {
+            match self.time_zone {
+                Some(zone) => self.next_occurrence_in(&zone, after),
+                None => self.next_occurrence_in(&Local, after),
+            }
+        }
+
+        /// Calcule le prochain déclenchement de l'alarme dans le fuseau horaire donné.
+        fn next_occurrence_in<Z: TimeZone>(&self, zone: &Z, after: DateTime<Local>) -> Option<DateTime<Local>> {
+            let time = NaiveTime::from_hms_opt(self.horaire.hour as u32, self.horaire.minute as u32, self.horaire.second as u32)?;
+            if let Some(date) = self.date {
+                return self.dst.resolve(zone, date.and_time(time))
+                    .map(|candidate: DateTime<Z>| candidate.with_timezone(&Local))
+                    .filter(|candidate: &DateTime<Local>| *candidate > after);
+            }
+            let today = after.with_timezone(zone).date_naive();
+            for offset in 0..8 {
+                let date = today + Duration::days(offset);
+                if !self.days[date.weekday().num_days_from_monday() as usize] {
+                    continue;
+                }
+                if let Some(candidate) = self.dst.resolve(zone, date.and_time(time)) {
+                    let candidate = candidate.with_timezone(&Local);
+                    if candidate > after {
+                        return Some(candidate);
+                    }
+                }
+            }
+            None
+        }
+
+        /// Retourne le déclenchement de l'alarme compris entre deux instants.
+        ///
+        /// # Parameters
+        ///
+        /// * `after`: Le début de l'intervalle, exclu.
+        /// * `until`: La fin de l'intervalle, incluse.
+        ///
+        /// # Returns
+        ///
+        /// La date du déclenchement, ou `None` si l'alarme ne sonne pas dans l'intervalle.
+        pub fn occurs_between(&self, after: DateTime<Local>, until: DateTime<Local>) -> Option<DateTime<Local>> {
+            self.next_occurrence(after).filter(|next: &DateTime<Local>| *next <= until)
+        }
+    }
+}
@@ -0,0 +1,161 @@
+pub mod player {
+    use chrono::{DateTime, Local};
+    use std::sync::{Arc, Mutex};
+    use tracing::info;
+    use crate::clockmod::clock::Clock;
+    use crate::errormod::error::{Error, Result};
+
+    pub trait Music {
+        /// Joue une source de musique.
+        ///
+        /// # Parameters
+        ///
+        /// * `source`: Le chemin ou l'URL de la source de musique.
+        ///
+        /// # Returns
+        ///
+        /// `Result<()>` - `Error::Playback` si la lecture n'a pas pu démarrer.
+        fn play(&mut self, source: String) -> Result<()>;
+
+        /// Arrête la lecture de la musique.
+        fn stop(&mut self);
+    }
+
+    /// Commande reçue par un `RecordingPlayer`.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Recorded {
+        /// Lecture d'une source (URL ou chemin).
+        Play(String),
+        /// Arrêt de la lecture.
+        Stop,
+    }
+
+    /// Commande reçue par un `RecordingPlayer`, avec son heure.
+    pub type Record = (DateTime<Local>, Recorded);
+
+    /// Lecteur sans sortie audio, qui enregistre les commandes reçues et leur heure.
+    ///
+    /// Il remplace `RadioPlayer` et `WavPlayer` là où il n'y a ni carte son ni réseau (tests,
+    /// intégration continue, simulation). Les copies partagent le même enregistrement.
+    #[derive(Clone)]
+    pub struct RecordingPlayer {
+        clock: Arc<dyn Clock>,
+        records: Arc<Mutex<Vec<Record>>>,
+        /// Erreur renvoyée par chaque lecture, pour simuler une source illisible.
+        failure: Option<String>,
+    }
+
+    impl RecordingPlayer {
+        /// Crée une nouvelle instance de `RecordingPlayer`.
+        ///
+        /// # Parameters
+        ///
+        /// * `clock`: L'horloge qui date les commandes.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `RecordingPlayer`, dont chaque lecture réussit.
+        pub fn new(clock: Arc<dyn Clock>) -> Self {
+            RecordingPlayer {
+                clock,
+                records: Arc::new(Mutex::new(Vec::new())),
+                failure: None,
+            }
+        }
+
+        /// Crée un `RecordingPlayer` dont chaque lecture échoue, pour tester les solutions de repli.
+        ///
+        /// # Parameters
+        ///
+        /// * `clock`: L'horloge qui date les commandes.
+        /// * `message`: Le message de l'`Error::Playback` renvoyée.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `RecordingPlayer` ; les lectures échouées sont tout de même enregistrées.
+        pub fn failing(clock: Arc<dyn Clock>, message: &str) -> Self {
+            RecordingPlayer {
+                failure: Some(message.to_string()),
+                ..RecordingPlayer::new(clock)
+            }
+        }
+
+        /// Retourne les commandes reçues, dans l'ordre, avec leur heure.
+        pub fn records(&self) -> Vec<Record> {
+            self.records.lock().unwrap().clone()
+        }
+
+        /// Retourne la source en cours de lecture, d'après la dernière commande reçue.
+        pub fn playing(&self) -> Option<String> {
+            match self.records.lock().unwrap().last() {
+                Some((_, Recorded::Play(source))) if self.failure.is_none() => Some(source.clone()),
+                _ => None,
+            }
+        }
+
+        /// Enregistre une commande à l'heure de l'horloge.
+        fn record(&self, command: Recorded) {
+            self.records.lock().unwrap().push((self.clock.now(), command));
+        }
+    }
+
+    impl Music for RecordingPlayer {
+        /// Enregistre la lecture d'une source, sans la jouer.
+        ///
+        /// # Parameters
+        ///
+        /// * `source`: Le chemin ou l'URL de la source de musique.
+        fn play(&mut self, source: String) -> Result<()> {
+            info!(%source, "Recorded play");
+            self.record(Recorded::Play(source));
+            match &self.failure {
+                Some(message) => Err(Error::Playback(message.clone())),
+                None => Ok(()),
+            }
+        }
+
+        /// Enregistre l'arrêt de la lecture.
+        fn stop(&mut self) {
+            self.record(Recorded::Stop);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use chrono::{Duration, TimeZone};
+        use crate::clockmod::clock::FakeClock;
+
+        #[test]
+        fn recording_player_timestamps_commands() {
+            let start = Local.with_ymd_and_hms(2026, 10, 19, 7, 0, 0).unwrap();
+            let clock = FakeClock::new(start);
+            let player = RecordingPlayer::new(Arc::new(clock.clone()));
+            // Le lecteur est confié à l'appelant ; la copie garde l'accès à l'enregistrement.
+            let mut music: Box<dyn Music> = Box::new(player.clone());
+
+            music.play("http://radio.example/stream".to_string()).unwrap();
+            assert_eq!(player.playing().as_deref(), Some("http://radio.example/stream"));
+            clock.advance(Duration::seconds(90));
+            music.stop();
+
+            assert_eq!(player.records(), vec![
+                (start, Recorded::Play("http://radio.example/stream".to_string())),
+                (start + Duration::seconds(90), Recorded::Stop),
+            ]);
+            assert!(player.playing().is_none());
+        }
+
+        #[test]
+        fn failing_player_reports_playback_error() {
+            let clock = FakeClock::new(Local::now());
+            let mut player = RecordingPlayer::failing(Arc::new(clock), "stream offline");
+
+            let result = player.play("http://radio.example/stream".to_string());
+
+            assert!(matches!(result, Err(Error::Playback(message)) if message == "stream offline"));
+            assert_eq!(player.records().len(), 1);
+            assert!(player.playing().is_none());
+        }
+    }
+}
@@ -0,0 +1,448 @@
+pub mod scheduler {
+    use chrono::{DateTime, Duration, Local};
+    use std::sync::Arc;
+    use tracing::{debug, info, trace, warn};
+    use crate::clockmod::clock::Clock;
+    use crate::historymod::history::{EventKind, History, HistoryEntry, Ringing};
+    use crate::modelmod::model::AlarmClock;
+
+    /// Durée du report d'une alarme, en minutes.
+    pub const SNOOZE_MINUTES: i64 = 5;
+
+    /// Retard au-delà duquel une alarme non déclenchée est considérée comme manquée, en secondes.
+    const MISSED_AFTER_SECS: i64 = 60;
+
+    /// Résultat d'une vérification des alarmes.
+    pub struct Tick {
+        /// L'alarme à faire sonner, s'il y en a une ; les autres alarmes dues attendent que la sonnerie
+        /// en cours soit arrêtée ou reportée.
+        pub fired: Option<AlarmClock>,
+        /// Les alarmes manquées depuis la vérification précédente, déjà enregistrées dans l'historique.
+        pub missed: Vec<AlarmClock>,
+        /// Les alarmes dont la commande `pre_alarm` doit être lancée.
+        pub pre_alarms: Vec<AlarmClock>,
+    }
+
+    /// Planificateur des alarmes, indépendant de l'interface graphique.
+    ///
+    /// Il décide quelles alarmes doivent sonner à chaque vérification, gère les reports
+    /// et tient l'historique des événements. La lecture audio reste à la charge de l'appelant.
+    /// L'heure est lue sur l'horloge fournie, ce qui permet de simuler le temps.
+    pub struct Scheduler {
+        clock: Arc<dyn Clock>,
+        alarms: Vec<AlarmClock>,
+        last_check: Option<DateTime<Local>>,
+        /// Alarmes en attente et l'heure à laquelle elles sonnent : reportées, ou dues pendant qu'une autre sonne.
+        snoozed: Vec<(AlarmClock, DateTime<Local>)>,
+        ringing: Option<Ringing>,
+        history: History,
+    }
+
+    impl Scheduler {
+        /// Crée une nouvelle instance de `Scheduler`.
+        ///
+        /// # Parameters
+        ///
+        /// * `history`: L'historique dans lequel enregistrer les événements.
+        /// * `clock`: L'horloge de l'application.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `Scheduler` sans alarme.
+        pub fn new(history: History, clock: Arc<dyn Clock>) -> Self {
+            Scheduler {
+                clock,
+                alarms: Vec::new(),
+                last_check: None,
+                snoozed: Vec::new(),
+                ringing: None,
+                history,
+            }
+        }
+
+        /// Remplace les alarmes planifiées.
+        ///
+        /// Les alarmes en attente supprimées ou désactivées ne sonnent plus ; celles modifiées
+        /// sonnent avec leurs nouveaux réglages.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarms`: Les nouvelles alarmes.
+        pub fn set_alarms(&mut self, alarms: Vec<AlarmClock>) {
+            self.snoozed.retain_mut(|(pending, _)| {
+                match alarms.iter().find(|alarm: &&AlarmClock| alarm.a_id == pending.a_id && alarm.active) {
+                    Some(alarm) => {
+                        *pending = alarm.clone();
+                        true
+                    }
+                    None => {
+                        debug!(alarm_id = pending.a_id, "Pending alarm removed or disabled, dropped");
+                        false
+                    }
+                }
+            });
+            self.alarms = alarms;
+        }
+
+        /// Retourne l'instant présent selon l'horloge du planificateur.
+        pub fn now(&self) -> DateTime<Local> {
+            self.clock.now()
+        }
+
+        /// Retourne les alarmes planifiées.
+        pub fn alarms(&self) -> &[AlarmClock] {
+            &self.alarms
+        }
+
+        /// Retourne l'historique des événements.
+        pub fn history(&self) -> &History {
+            &self.history
+        }
+
+        /// Retourne l'alarme en train de sonner, s'il y en a une.
+        pub fn ringing(&self) -> Option<&Ringing> {
+            self.ringing.as_ref()
+        }
+
+        /// Vérifie les alarmes à l'instant présent.
+        ///
+        /// Chaque occurrence comprise entre la vérification précédente et maintenant est déclenchée
+        /// une seule fois, même si des vérifications sont sautées ; au-delà de
+        /// `MISSED_AFTER_SECS` de retard, elle est enregistrée comme manquée. Une seule alarme sonne
+        /// à la fois : les autres alarmes dues sont mises en attente et sonnent, une par vérification,
+        /// une fois la sonnerie en cours arrêtée ou reportée.
+        ///
+        /// # Returns
+        ///
+        /// L'alarme à faire sonner, les alarmes manquées et celles dont la commande `pre_alarm` est due.
+        pub fn tick(&mut self) -> Tick {
+            let now = self.clock.now();
+            let last_check = self.last_check.replace(now);
+
+            let mut due_alarms: Vec<AlarmClock> = Vec::new();
+            let mut missed: Vec<AlarmClock> = Vec::new();
+            for alarm in self.alarms.iter().filter(|alarm: &&AlarmClock| alarm.active) {
+                let due = match last_check {
+                    Some(last_check) => match alarm.next_occurrence(last_check) {
+                        Some(next) if next <= now => next,
+                        next => {
+                            trace!(alarm_id = alarm.a_id, next = ?next, "Alarm not due");
+                            continue;
+                        }
+                    },
+                    // Première vérification : seule une alarme prévue dans la seconde en cours sonne.
+                    None if alarm.occurs_between(now - Duration::seconds(1), now).is_some() => now,
+                    None => {
+                        trace!(alarm_id = alarm.a_id, "Alarm not due at first check");
+                        continue;
+                    }
+                };
+                let late_secs = (now - due).num_seconds();
+                if late_secs > MISSED_AFTER_SECS {
+                    warn!(alarm_id = alarm.a_id, %due, late_secs, "Alarm missed");
+                    missed.push(alarm.clone());
+                } else {
+                    info!(alarm_id = alarm.a_id, %due, late_secs, "Alarm due, firing");
+                    due_alarms.push(alarm.clone());
+                }
+            }
+            for alarm in missed.iter() {
+                self.history.record(HistoryEntry::new(alarm, EventKind::Missed, None, now));
+            }
+            let pre_alarms = match last_check {
+                Some(last_check) => self.pre_alarms(last_check, now),
+                None => Vec::new(),
+            };
+
+            let mut due_alarms = due_alarms.into_iter();
+            let mut fired = None;
+            if self.ringing.is_none() {
+                fired = due_alarms.next();
+                if let Some(index) = self.snoozed.iter().position(|(_, due)| *due <= now) {
+                    let (snoozed_alarm, _) = self.snoozed.remove(index);
+                    info!(alarm_id = snoozed_alarm.a_id, "Snoozed alarm due, firing");
+                    // L'alarme régulière sonnera après celle-ci.
+                    if let Some(alarm) = fired.replace(snoozed_alarm) {
+                        debug!(alarm_id = alarm.a_id, "Alarm postponed behind snoozed alarm");
+                        self.snoozed.push((alarm, now));
+                    }
+                }
+            }
+            for alarm in due_alarms {
+                debug!(alarm_id = alarm.a_id, "Alarm due while another fires, postponed");
+                self.snoozed.push((alarm, now));
+            }
+            Tick { fired, missed, pre_alarms }
+        }
+
+        /// Retourne les alarmes dont l'instant `pre_alarm_minutes` avant le déclenchement
+        /// est compris entre `last_check` (exclu) et `now` (inclus).
+        fn pre_alarms(&self, last_check: DateTime<Local>, now: DateTime<Local>) -> Vec<AlarmClock> {
+            self.alarms.iter()
+                .filter(|alarm: &&AlarmClock| alarm.active && !alarm.hooks.pre_alarm.is_empty())
+                .filter(|alarm: &&AlarmClock| {
+                    let lead = Duration::minutes(alarm.hooks.pre_alarm_minutes as i64);
+                    alarm.occurs_between(last_check + lead, now + lead).is_some()
+                })
+                .cloned()
+                .collect()
+        }
+
+        /// Enregistre le déclenchement d'une alarme, qui devient l'alarme en train de sonner.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarm`: L'alarme déclenchée.
+        pub fn fired(&mut self, alarm: AlarmClock) {
+            let now = self.clock.now();
+            self.history.record(HistoryEntry::new(&alarm, EventKind::Triggered, None, now));
+            self.ringing = Some(Ringing::new(alarm, now));
+        }
+
+        /// Enregistre l'échec de lecture d'une alarme.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarm`: L'alarme qui n'a pas pu être jouée.
+        pub fn failed(&mut self, alarm: &AlarmClock) {
+            self.history.record(HistoryEntry::new(alarm, EventKind::PlaybackFailed, None, self.clock.now()));
+        }
+
+        /// Arrête l'alarme en train de sonner.
+        ///
+        /// # Returns
+        ///
+        /// L'alarme arrêtée, ou `None` si aucune alarme ne sonnait.
+        pub fn dismiss(&mut self) -> Option<AlarmClock> {
+            let ringing = self.ringing.take()?;
+            self.history.record(ringing.finish(EventKind::Dismissed, self.clock.now()));
+            Some(ringing.alarm)
+        }
+
+        /// Arrête l'alarme qui sonne depuis trop longtemps, sans action de l'utilisateur.
+        ///
+        /// Contrairement à `dismiss`, l'arrêt est enregistré comme `TimedOut` : il ne compte pas
+        /// dans le temps moyen avant arrêt.
+        ///
+        /// # Returns
+        ///
+        /// L'alarme arrêtée, ou `None` si aucune alarme ne sonnait.
+        pub fn time_out(&mut self) -> Option<AlarmClock> {
+            let ringing = self.ringing.take()?;
+            self.history.record(ringing.finish(EventKind::TimedOut, self.clock.now()));
+            Some(ringing.alarm)
+        }
+
+        /// Reporte l'alarme en train de sonner de `SNOOZE_MINUTES` minutes.
+        ///
+        /// # Returns
+        ///
+        /// L'alarme reportée et l'heure à laquelle elle sonnera de nouveau, ou `None` si aucune alarme ne sonnait.
+        pub fn snooze(&mut self) -> Option<(AlarmClock, DateTime<Local>)> {
+            let now = self.clock.now();
+            let ringing = self.ringing.take()?;
+            self.history.record(ringing.finish(EventKind::Snoozed, now));
+            let due = now + Duration::minutes(SNOOZE_MINUTES);
+            self.snoozed.push((ringing.alarm.clone(), due));
+            Some((ringing.alarm, due))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use chrono::{Datelike, TimeZone, Weekday};
+        use crate::clockmod::clock::FakeClock;
+        use crate::historymod::history::Statistics;
+        use crate::testmod::fixtures::alarm;
+
+        const WEEKDAYS: [bool; 7] = [true, true, true, true, true, false, false];
+        const WEEKEND: [bool; 7] = [false, false, false, false, false, true, true];
+
+        /// Lundi 19 octobre 2026 à minuit.
+        fn monday() -> DateTime<Local> {
+            Local.with_ymd_and_hms(2026, 10, 19, 0, 0, 0).unwrap()
+        }
+
+        fn scheduler(clock: &FakeClock) -> Scheduler {
+            Scheduler::new(History::default(), Arc::new(clock.clone()))
+        }
+
+        #[test]
+        fn simulates_a_week_of_alarms() {
+            let clock = FakeClock::new(monday());
+            let mut scheduler = scheduler(&clock);
+            let mut lunch = alarm(2, 12, 0, [true; 7]);
+            lunch.active = false;
+            scheduler.set_alarms(vec![alarm(0, 7, 0, WEEKDAYS), alarm(1, 9, 30, WEEKEND), lunch]);
+
+            // Chaque alarme sonne une minute puis est arrêtée ; la première du mercredi est reportée.
+            let mut rang: Vec<String> = Vec::new();
+            let mut snoozed = false;
+            let end = monday() + Duration::days(7);
+            while clock.now() < end {
+                clock.advance(Duration::seconds(10));
+                let tick = scheduler.tick();
+                assert!(tick.missed.is_empty());
+                if let Some(alarm) = tick.fired {
+                    rang.push(format!("{} {}", clock.now().format("%a %H:%M"), alarm.a_id));
+                    scheduler.fired(alarm);
+                }
+                let finished = scheduler.ringing().is_some_and(|ringing: &Ringing| clock.now() - ringing.started >= Duration::minutes(1));
+                if finished {
+                    if clock.now().weekday() == Weekday::Wed && !snoozed {
+                        snoozed = true;
+                        let (_, due) = scheduler.snooze().unwrap();
+                        assert_eq!(due.format("%H:%M").to_string(), "07:06");
+                    } else {
+                        scheduler.dismiss().unwrap();
+                    }
+                }
+            }
+
+            assert_eq!(rang, vec![
+                "Mon 07:00 0", "Tue 07:00 0", "Wed 07:00 0", "Wed 07:06 0",
+                "Thu 07:00 0", "Fri 07:00 0", "Sat 09:30 1", "Sun 09:30 1",
+            ]);
+            let entries = &scheduler.history().entries;
+            let count = |kind: EventKind| entries.iter().filter(|entry: &&HistoryEntry| entry.kind == kind).count();
+            assert_eq!(count(EventKind::Triggered), 8);
+            assert_eq!(count(EventKind::Snoozed), 1);
+            assert_eq!(count(EventKind::Dismissed), 7);
+            assert!(entries.iter().all(|entry: &HistoryEntry| entry.rang_for.is_none_or(|secs: i64| secs == 60)));
+        }
+
+        #[test]
+        fn alarms_due_together_fire_one_after_the_other() {
+            let clock = FakeClock::new(monday() + Duration::hours(6));
+            let mut scheduler = scheduler(&clock);
+            scheduler.set_alarms(vec![alarm(0, 7, 0, WEEKDAYS), alarm(1, 7, 0, WEEKDAYS), alarm(2, 7, 0, [true; 7])]);
+            scheduler.tick();
+
+            clock.set(monday() + Duration::hours(7));
+            let mut rang = Vec::new();
+            for _ in 0..4 {
+                if let Some(alarm) = scheduler.tick().fired {
+                    rang.push(alarm.a_id);
+                    scheduler.fired(alarm);
+                    scheduler.dismiss();
+                }
+                clock.advance(Duration::seconds(1));
+            }
+
+            assert_eq!(rang, vec![0, 1, 2]);
+        }
+
+        #[test]
+        fn coincident_alarm_waits_for_the_ringing_one_to_stop() {
+            let clock = FakeClock::new(monday() + Duration::hours(6));
+            let mut scheduler = scheduler(&clock);
+            scheduler.set_alarms(vec![alarm(0, 7, 0, WEEKDAYS), alarm(1, 7, 0, WEEKDAYS)]);
+            scheduler.tick();
+
+            clock.set(monday() + Duration::hours(7));
+            let first = scheduler.tick().fired.unwrap();
+            scheduler.fired(first);
+            // L'alarme 1 attend l'arrêt de l'alarme 0, qui sonne 30 s.
+            for _ in 0..30 {
+                clock.advance(Duration::seconds(1));
+                assert!(scheduler.tick().fired.is_none());
+            }
+            scheduler.dismiss().unwrap();
+            clock.advance(Duration::seconds(1));
+            let second = scheduler.tick().fired.unwrap();
+            assert_eq!(second.a_id, 1);
+            scheduler.fired(second);
+            clock.advance(Duration::minutes(15));
+            scheduler.time_out().unwrap();
+
+            let entries: Vec<(usize, EventKind, Option<i64>)> = scheduler.history().entries.iter()
+                .map(|entry: &HistoryEntry| (entry.alarm_id, entry.kind, entry.rang_for))
+                .collect();
+            assert_eq!(entries, vec![
+                (0, EventKind::Triggered, None),
+                (0, EventKind::Dismissed, Some(30)),
+                (1, EventKind::Triggered, None),
+                (1, EventKind::TimedOut, Some(15 * 60)),
+            ]);
+        }
+
+        #[test]
+        fn removed_or_disabled_alarms_stop_waiting() {
+            let clock = FakeClock::new(monday() + Duration::hours(6));
+            let mut scheduler = scheduler(&clock);
+            let alarms = vec![alarm(0, 7, 0, WEEKDAYS), alarm(1, 7, 0, WEEKDAYS), alarm(2, 7, 0, WEEKDAYS)];
+            scheduler.set_alarms(alarms.clone());
+            scheduler.tick();
+
+            // L'alarme 0 sonne puis est reportée ; les alarmes 1 et 2 attendent.
+            clock.set(monday() + Duration::hours(7));
+            let first = scheduler.tick().fired.unwrap();
+            scheduler.fired(first);
+            scheduler.snooze().unwrap();
+
+            // L'alarme 0 est supprimée, la 1 désactivée et la 2 renommée.
+            let mut disabled = alarms[1].clone();
+            disabled.active = false;
+            let mut renamed = alarms[2].clone();
+            renamed.name = "Sport".to_string();
+            scheduler.set_alarms(vec![disabled, renamed]);
+
+            let mut rang = Vec::new();
+            while clock.now() < monday() + Duration::minutes(7 * 60 + 10) {
+                clock.advance(Duration::seconds(10));
+                if let Some(alarm) = scheduler.tick().fired {
+                    rang.push((alarm.a_id, alarm.name.clone()));
+                    scheduler.fired(alarm);
+                    scheduler.dismiss();
+                }
+            }
+            assert_eq!(rang, vec![(2, "Sport".to_string())]);
+        }
+
+        #[test]
+        fn timed_out_ringing_is_not_a_dismissal() {
+            let clock = FakeClock::new(monday() + Duration::hours(7));
+            let mut scheduler = scheduler(&clock);
+            scheduler.fired(alarm(0, 7, 0, WEEKDAYS));
+            clock.advance(Duration::minutes(15));
+
+            assert_eq!(scheduler.time_out().map(|alarm: AlarmClock| alarm.a_id), Some(0));
+            assert!(scheduler.ringing().is_none());
+            let stats = Statistics::from_history(scheduler.history());
+            assert_eq!(stats.timed_out, 1);
+            assert_eq!(stats.average_dismiss_secs, None);
+        }
+
+        #[test]
+        fn skipped_checks_record_missed_alarm() {
+            let clock = FakeClock::new(monday() + Duration::hours(6));
+            let mut scheduler = scheduler(&clock);
+            scheduler.set_alarms(vec![alarm(0, 7, 0, WEEKDAYS)]);
+            scheduler.tick();
+
+            // Machine en veille de 06:00 à 07:05 : aucune vérification entre les deux.
+            clock.set(monday() + Duration::minutes(7 * 60 + 5));
+            let tick = scheduler.tick();
+
+            assert!(tick.fired.is_none());
+            assert_eq!(tick.missed.len(), 1);
+            assert_eq!(scheduler.history().entries[0].kind, EventKind::Missed);
+            assert_eq!(scheduler.history().entries[0].timestamp, clock.now());
+        }
+
+        #[test]
+        fn late_check_within_tolerance_still_fires() {
+            let clock = FakeClock::new(monday() + Duration::hours(6));
+            let mut scheduler = scheduler(&clock);
+            scheduler.set_alarms(vec![alarm(0, 7, 0, WEEKDAYS)]);
+            scheduler.tick();
+
+            clock.set(monday() + Duration::seconds(7 * 3600 + 30));
+            let tick = scheduler.tick();
+
+            assert_eq!(tick.fired.map(|alarm: AlarmClock| alarm.a_id), Some(0));
+            assert!(tick.missed.is_empty());
+        }
+    }
+}
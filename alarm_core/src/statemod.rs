@@ -0,0 +1,418 @@
+pub mod state {
+    use std::sync::{Arc, Mutex};
+    use std::time::SystemTime;
+    use tokio::sync::broadcast;
+    use tokio::time::{interval, Duration};
+    use crate::errormod::error::{Error, Result};
+    use crate::modelmod::model::{self, AlarmClock, RadioStation};
+    use crate::timermod::timer::{self, Timer};
+
+    /// Changement de l'état de l'application, diffusé aux abonnés du `Store`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Change {
+        /// Les alarmes ont été modifiées par l'application et doivent être sauvegardées.
+        Alarms,
+        /// Les alarmes ont été relues depuis le fichier de sauvegarde, modifié par un autre processus.
+        AlarmsReloaded,
+        /// Le lecteur a démarré ou s'est arrêté.
+        Player,
+        /// La station de radio sélectionnée a changé.
+        Radio,
+        /// Les minuteurs ont été créés, modifiés ou supprimés et doivent être sauvegardés.
+        Timers,
+    }
+
+    /// État du lecteur de musique et de radio.
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct PlayerStatus {
+        /// Indique si une musique ou une radio est en cours de lecture.
+        pub playing: bool,
+        /// La source jouée (nom de la station ou chemin du fichier).
+        pub source: Option<String>,
+    }
+
+    /// État partagé de l'application.
+    #[derive(Clone, Default)]
+    pub struct AppState {
+        /// Les alarmes, dans l'ordre de création.
+        pub alarms: Vec<AlarmClock>,
+        /// L'état du lecteur.
+        pub player: PlayerStatus,
+        /// La station de radio sélectionnée.
+        pub selected_radio: Option<RadioStation>,
+        /// Les minuteurs, dans l'ordre de création.
+        pub timers: Vec<Timer>,
+    }
+
+    /// Source unique de l'état de l'application.
+    ///
+    /// Les copies d'un `Store` partagent le même état : une modification faite par l'une est
+    /// immédiatement visible des autres, et chaque modification est diffusée aux abonnés
+    /// (affichage, planificateur, sauvegarde).
+    #[derive(Clone)]
+    pub struct Store {
+        state: Arc<Mutex<AppState>>,
+        changes: broadcast::Sender<Change>,
+    }
+
+    impl Store {
+        /// Crée une nouvelle instance de `Store`.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarms`: Les alarmes initiales.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `Store`, lecteur arrêté et sans radio sélectionnée.
+        pub fn new(alarms: Vec<AlarmClock>) -> Self {
+            let (changes, _) = broadcast::channel(64);
+            Store {
+                state: Arc::new(Mutex::new(AppState { alarms, ..AppState::default() })),
+                changes,
+            }
+        }
+
+        /// Crée un `Store` à partir des alarmes et des minuteurs sauvegardés.
+        ///
+        /// Les deux fichiers sont lus indépendamment : un fichier de minuteurs illisible
+        /// n'empêche pas les alarmes de sonner.
+        ///
+        /// # Returns
+        ///
+        /// Le `Store` et les erreurs de lecture ; un fichier illisible est remplacé par une liste vide.
+        pub fn load() -> (Self, Vec<Error>) {
+            Store::from_saved(model::load_alarms(), timer::load_timers())
+        }
+
+        /// Crée un `Store` à partir des résultats de lecture des alarmes et des minuteurs.
+        fn from_saved(alarms: Result<Vec<AlarmClock>>, timers: Result<Vec<Timer>>) -> (Self, Vec<Error>) {
+            let mut errors = Vec::new();
+            let store = Store::new(alarms.unwrap_or_else(|error: Error| {
+                errors.push(error);
+                Vec::new()
+            }));
+            store.state.lock().unwrap().timers = timers.unwrap_or_else(|error: Error| {
+                errors.push(error);
+                Vec::new()
+            });
+            (store, errors)
+        }
+
+        /// S'abonne aux changements de l'état.
+        ///
+        /// # Returns
+        ///
+        /// Un récepteur des changements faits après l'abonnement.
+        pub fn subscribe(&self) -> broadcast::Receiver<Change> {
+            self.changes.subscribe()
+        }
+
+        /// Retourne une copie de l'état courant.
+        pub fn snapshot(&self) -> AppState {
+            self.state.lock().unwrap().clone()
+        }
+
+        /// Retourne une copie des alarmes.
+        pub fn alarms(&self) -> Vec<AlarmClock> {
+            self.state.lock().unwrap().alarms.clone()
+        }
+
+        /// Retourne une copie des minuteurs.
+        pub fn timers(&self) -> Vec<Timer> {
+            self.state.lock().unwrap().timers.clone()
+        }
+
+        /// Retourne l'état du lecteur.
+        pub fn player(&self) -> PlayerStatus {
+            self.state.lock().unwrap().player.clone()
+        }
+
+        /// Retourne la station de radio sélectionnée.
+        pub fn selected_radio(&self) -> Option<RadioStation> {
+            self.state.lock().unwrap().selected_radio.clone()
+        }
+
+        /// Modifie les alarmes puis diffuse `Change::Alarms`.
+        ///
+        /// # Parameters
+        ///
+        /// * `edit`: La modification à appliquer.
+        ///
+        /// # Returns
+        ///
+        /// La valeur retournée par `edit`.
+        pub fn update_alarms<R>(&self, edit: impl FnOnce(&mut Vec<AlarmClock>) -> R) -> R {
+            let result = edit(&mut self.state.lock().unwrap().alarms);
+            self.notify(Change::Alarms);
+            result
+        }
+
+        /// Modifie les minuteurs puis diffuse `Change::Timers`.
+        ///
+        /// # Parameters
+        ///
+        /// * `edit`: La modification à appliquer.
+        ///
+        /// # Returns
+        ///
+        /// La valeur retournée par `edit`.
+        pub fn update_timers<R>(&self, edit: impl FnOnce(&mut Vec<Timer>) -> R) -> R {
+            let result = edit(&mut self.state.lock().unwrap().timers);
+            self.notify(Change::Timers);
+            result
+        }
+
+        /// Remplace les alarmes par celles relues du fichier de sauvegarde, puis diffuse `Change::AlarmsReloaded`.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarms`: Les alarmes relues.
+        pub fn reload_alarms(&self, alarms: Vec<AlarmClock>) {
+            self.state.lock().unwrap().alarms = alarms;
+            self.notify(Change::AlarmsReloaded);
+        }
+
+        /// Met à jour l'état du lecteur, puis diffuse `Change::Player` s'il a changé.
+        ///
+        /// # Parameters
+        ///
+        /// * `player`: Le nouvel état du lecteur.
+        pub fn set_player(&self, player: PlayerStatus) {
+            let changed = {
+                let mut state = self.state.lock().unwrap();
+                let changed = state.player != player;
+                state.player = player;
+                changed
+            };
+            if changed {
+                self.notify(Change::Player);
+            }
+        }
+
+        /// Sélectionne une station de radio, puis diffuse `Change::Radio`.
+        ///
+        /// # Parameters
+        ///
+        /// * `station`: La station sélectionnée.
+        pub fn select_radio(&self, station: Option<RadioStation>) {
+            self.state.lock().unwrap().selected_radio = station;
+            self.notify(Change::Radio);
+        }
+
+        /// Diffuse un changement, une fois le verrou relâché.
+        ///
+        /// # Parameters
+        ///
+        /// * `change`: Le changement à diffuser.
+        fn notify(&self, change: Change) {
+            // Aucun abonné n'est pas une erreur.
+            let _ = self.changes.send(change);
+        }
+    }
+
+    /// Sauvegarde des alarmes et des minuteurs, abonnée au `Store`.
+    ///
+    /// Chaque modification des alarmes ou des minuteurs est écrite dans le fichier de sauvegarde ; une
+    /// modification du fichier par un autre processus (ligne de commande) est relue dans le `Store`.
+    pub struct Persistence {
+        store: Store,
+        changes: broadcast::Receiver<Change>,
+        last_modified: Option<SystemTime>,
+        /// Les alarmes telles qu'écrites ou relues pour la dernière fois.
+        synced: Vec<AlarmClock>,
+    }
+
+    impl Persistence {
+        /// Crée une nouvelle instance de `Persistence`.
+        ///
+        /// # Parameters
+        ///
+        /// * `store`: Le `Store` à sauvegarder.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `Persistence`, abonnée aux changements du `Store`.
+        pub fn new(store: Store) -> Self {
+            Persistence {
+                changes: store.subscribe(),
+                synced: store.alarms(),
+                store,
+                last_modified: model::alarms_modified(),
+            }
+        }
+
+        /// Écrit les alarmes du `Store` dans le fichier de sauvegarde.
+        ///
+        /// Si un autre processus a modifié le fichier depuis la dernière synchronisation, ses
+        /// changements sont fusionnés avec ceux de l'application avant l'écriture, puis relus dans le `Store`.
+        ///
+        /// # Returns
+        ///
+        /// `Result<()>` - Résultat de l'opération de sauvegarde ; un fichier illisible n'est pas écrasé.
+        pub fn save(&mut self) -> Result<()> {
+            let mut alarms = self.store.alarms();
+            let saved = model::load_alarms()?;
+            if saved != self.synced {
+                alarms = merge(&self.synced, &alarms, &saved);
+                self.store.reload_alarms(alarms.clone());
+            }
+            model::save_alarms(&alarms)?;
+            self.last_modified = model::alarms_modified();
+            self.synced = alarms;
+            Ok(())
+        }
+
+        /// Écrit les minuteurs du `Store` dans leur fichier de sauvegarde.
+        ///
+        /// # Returns
+        ///
+        /// `Result<()>` - Résultat de l'opération de sauvegarde.
+        pub fn save_timers(&self) -> Result<()> {
+            timer::save_timers(&self.store.timers())
+        }
+
+        /// Relit le fichier de sauvegarde s'il a été modifié par un autre processus.
+        ///
+        /// # Returns
+        ///
+        /// `Result<bool>` - `true` si les alarmes ont été relues.
+        pub fn reload_if_modified(&mut self) -> Result<bool> {
+            let modified = model::alarms_modified();
+            if modified == self.last_modified {
+                return Ok(false);
+            }
+            self.last_modified = modified;
+            let alarms = model::load_alarms()?;
+            self.synced = alarms.clone();
+            self.store.reload_alarms(alarms);
+            Ok(true)
+        }
+
+        /// Sauvegarde les alarmes et les minuteurs à chaque changement et surveille le fichier chaque seconde.
+        ///
+        /// # Parameters
+        ///
+        /// * `on_sync`: Appelée après chaque sauvegarde ou relecture, avec son résultat.
+        pub async fn run(mut self, mut on_sync: impl FnMut(Result<()>) + Send) {
+            let mut ticker = interval(Duration::from_secs(1));
+            loop {
+                tokio::select! {
+                    change = self.changes.recv() => match change {
+                        // Des changements perdus imposent aussi une sauvegarde.
+                        Ok(Change::Alarms) => on_sync(self.save()),
+                        Ok(Change::Timers) => on_sync(self.save_timers()),
+                        Err(broadcast::error::RecvError::Lagged(_)) => on_sync(self.save().and_then(|()| self.save_timers())),
+                        Ok(_) => {}
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = ticker.tick() => match self.reload_if_modified() {
+                        Ok(true) => on_sync(Ok(())),
+                        Ok(false) => {}
+                        Err(error) => on_sync(Err(error)),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Fusionne les alarmes modifiées par l'application et celles modifiées par un autre processus.
+    ///
+    /// # Parameters
+    ///
+    /// * `base`: Les alarmes à la dernière synchronisation.
+    /// * `ours`: Les alarmes de l'application.
+    /// * `theirs`: Les alarmes du fichier de sauvegarde.
+    ///
+    /// # Returns
+    ///
+    /// Les alarmes du fichier, dans leur ordre, où chaque alarme ajoutée, modifiée ou supprimée par
+    /// l'application depuis `base` prend la version de l'application.
+    fn merge(base: &[AlarmClock], ours: &[AlarmClock], theirs: &[AlarmClock]) -> Vec<AlarmClock> {
+        let find = |alarms: &[AlarmClock], a_id: usize| alarms.iter().find(|alarm: &&AlarmClock| alarm.a_id == a_id).cloned();
+        let changed = |a_id: usize| find(base, a_id) != find(ours, a_id);
+        let mut merged: Vec<AlarmClock> = theirs.iter()
+            .filter_map(|alarm: &AlarmClock| match changed(alarm.a_id) {
+                true => find(ours, alarm.a_id),
+                false => Some(alarm.clone()),
+            })
+            .collect();
+        for alarm in ours.iter() {
+            if changed(alarm.a_id) && find(theirs, alarm.a_id).is_none() {
+                merged.push(alarm.clone());
+            }
+        }
+        merged
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::testmod::fixtures::alarm;
+
+        #[test]
+        fn merge_keeps_changes_from_both_sides() {
+            let base = vec![alarm(0, 6, 0, [true; 7]), alarm(1, 7, 0, [true; 7]), alarm(2, 8, 0, [true; 7])];
+
+            // L'application désactive l'alarme 0 et ajoute l'alarme 3.
+            let mut ours = base.clone();
+            ours[0].active = false;
+            ours.push(alarm(3, 9, 0, [true; 7]));
+            // La ligne de commande supprime l'alarme 1, renomme la 2 et ajoute la 4.
+            let mut theirs = vec![base[0].clone(), base[2].clone(), alarm(4, 10, 0, [true; 7])];
+            theirs[1].name = "Sport".to_string();
+
+            let merged = merge(&base, &ours, &theirs);
+            let ids: Vec<usize> = merged.iter().map(|alarm: &AlarmClock| alarm.a_id).collect();
+            assert_eq!(ids, vec![0, 2, 4, 3]);
+            assert!(!merged[0].active);
+            assert_eq!(merged[1].name, "Sport");
+
+            // Une alarme supprimée par l'application le reste.
+            let ours = vec![base[0].clone(), base[1].clone()];
+            let ids: Vec<usize> = merge(&base, &ours, &theirs).iter().map(|alarm: &AlarmClock| alarm.a_id).collect();
+            assert_eq!(ids, vec![0, 4]);
+        }
+
+        #[test]
+        fn copies_share_one_state() {
+            let store = Store::new(vec![alarm(0, 7, 0, [true; 7])]);
+            let other = store.clone();
+            store.update_alarms(|alarms: &mut Vec<AlarmClock>| alarms[0].active = false);
+            other.set_player(PlayerStatus { playing: true, source: Some("France Inter".to_string()) });
+
+            assert!(!other.alarms()[0].active);
+            assert!(store.player().playing);
+        }
+
+        #[test]
+        fn unreadable_timers_keep_the_alarms() {
+            let corrupt = serde_json::from_str::<Vec<Timer>>("[{").map_err(Error::from);
+            let (store, errors) = Store::from_saved(Ok(vec![alarm(0, 7, 0, [true; 7]), alarm(1, 7, 0, [true; 7])]), corrupt);
+
+            assert_eq!(store.alarms().len(), 2);
+            assert!(store.timers().is_empty());
+            assert_eq!(errors.len(), 1);
+        }
+
+        #[test]
+        fn subscribers_are_notified_of_each_change() {
+            let store = Store::new(Vec::new());
+            let mut changes = store.subscribe();
+            store.update_alarms(|alarms: &mut Vec<AlarmClock>| alarms.push(alarm(0, 7, 0, [true; 7])));
+            store.set_player(PlayerStatus { playing: true, source: None });
+            // Même état : rien n'est diffusé.
+            store.set_player(PlayerStatus { playing: true, source: None });
+            store.select_radio(Some(RadioStation::RTL));
+            store.reload_alarms(Vec::new());
+            store.update_timers(|timers: &mut Vec<Timer>| timers.clear());
+
+            assert_eq!(changes.try_recv().unwrap(), Change::Alarms);
+            assert_eq!(changes.try_recv().unwrap(), Change::Player);
+            assert_eq!(changes.try_recv().unwrap(), Change::Radio);
+            assert_eq!(changes.try_recv().unwrap(), Change::AlarmsReloaded);
+            assert_eq!(changes.try_recv().unwrap(), Change::Timers);
+            assert!(changes.try_recv().is_err());
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod fixtures {
+    use crate::modelmod::model::{AlarmClock, RadioStation};
+
+    /// Alarme radio « Alarme <a_id> » sur France Inter.
+    pub fn alarm(a_id: usize, hour: u8, minute: u8, days: [bool; 7]) -> AlarmClock {
+        AlarmClock::new(a_id, format!("Alarme {}", a_id), hour, minute, 0, true, Some(RadioStation::FranceInter), days)
+    }
+}
@@ -0,0 +1,289 @@
+pub mod timer {
+    use chrono::{DateTime, Duration, Local};
+    use serde::{Serialize, Deserialize};
+    use std::fs::{self, File};
+    use std::io::{self, Write};
+    use std::path::Path;
+    use crate::errormod::error::Result;
+
+    const TIMERS_PATH: &str = "ser/timers.json";
+
+    /// Charge les minuteurs depuis le fichier de sauvegarde.
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Timer>>` - Les minuteurs sauvegardés, vide si le fichier est vide ou absent.
+    pub fn load_timers() -> Result<Vec<Timer>> {
+        load_timers_from(Path::new(TIMERS_PATH))
+    }
+
+    /// Charge les minuteurs depuis un fichier donné.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Le chemin du fichier de sauvegarde.
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Timer>>` - Les minuteurs sauvegardés, vide si le fichier est vide ou absent.
+    pub fn load_timers_from(path: &Path) -> Result<Vec<Timer>> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error.into()),
+        };
+        if contents.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        let timers: Vec<Timer> = serde_json::from_str(&contents)?;
+        Ok(timers)
+    }
+
+    /// Sauvegarde les minuteurs dans le fichier de sauvegarde.
+    ///
+    /// # Parameters
+    ///
+    /// * `timers`: Les minuteurs à sauvegarder.
+    ///
+    /// # Returns
+    ///
+    /// `Result<()>` - Résultat de l'opération de sauvegarde.
+    pub fn save_timers(timers: &[Timer]) -> Result<()> {
+        save_timers_to(Path::new(TIMERS_PATH), timers)
+    }
+
+    /// Sauvegarde les minuteurs dans un fichier donné.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Le chemin du fichier de sauvegarde.
+    /// * `timers`: Les minuteurs à sauvegarder.
+    ///
+    /// # Returns
+    ///
+    /// `Result<()>` - Résultat de l'opération de sauvegarde.
+    pub fn save_timers_to(path: &Path, timers: &[Timer]) -> Result<()> {
+        let serialized = serde_json::to_string(timers)?;
+        let mut file = File::create(path)?;
+        file.write_all(serialized.as_bytes())?;
+        Ok(())
+    }
+
+    /// Retourne un identifiant libre pour un nouveau minuteur.
+    ///
+    /// # Parameters
+    ///
+    /// * `timers`: Les minuteurs existants.
+    ///
+    /// # Returns
+    ///
+    /// Un identifiant supérieur à tous les identifiants existants.
+    pub fn next_timer_id(timers: &[Timer]) -> usize {
+        timers.iter().map(|timer: &Timer| timer.id.saturating_add(1)).max().unwrap_or(0)
+    }
+
+    /// Minuteur proposé en un clic (ex. "Thé", 4 minutes).
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Preset {
+        pub name: String,
+        pub seconds: u32,
+    }
+
+    /// État d'un minuteur.
+    ///
+    /// Un minuteur en marche est sauvegardé avec son échéance, un minuteur en pause avec le
+    /// temps restant : un minuteur en marche continue de s'écouler pendant que l'application est fermée.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(tag = "state", rename_all = "snake_case")]
+    pub enum TimerState {
+        /// Le minuteur s'écoule et sonnera à `deadline`.
+        Running { deadline: DateTime<Local> },
+        /// Le minuteur est arrêté avec `remaining_secs` secondes restantes.
+        Paused { remaining_secs: i64 },
+        /// Le minuteur est arrivé à zéro à `since` et sonne jusqu'à son arrêt.
+        Ringing { since: DateTime<Local> },
+    }
+
+    /// Compte à rebours nommé.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Timer {
+        pub id: usize,
+        pub name: String,
+        /// Durée initiale, rétablie par `reset`.
+        pub duration_secs: i64,
+        pub state: TimerState,
+    }
+
+    impl Timer {
+        /// Crée un minuteur qui démarre aussitôt.
+        ///
+        /// # Parameters
+        ///
+        /// * `id`: L'identifiant du minuteur.
+        /// * `name`: Le nom du minuteur.
+        /// * `duration_secs`: La durée du compte à rebours, en secondes.
+        /// * `now`: L'instant présent, lu sur l'horloge de l'application.
+        ///
+        /// # Returns
+        ///
+        /// Un minuteur en marche, qui sonnera dans `duration_secs` secondes.
+        pub fn start(id: usize, name: String, duration_secs: i64, now: DateTime<Local>) -> Self {
+            Timer {
+                id,
+                name,
+                duration_secs,
+                state: TimerState::Running { deadline: now + Duration::seconds(duration_secs) },
+            }
+        }
+
+        /// Retourne le temps restant avant la sonnerie.
+        ///
+        /// # Parameters
+        ///
+        /// * `now`: L'instant présent.
+        ///
+        /// # Returns
+        ///
+        /// Le temps restant, nul si le minuteur sonne ou a dépassé son échéance.
+        pub fn remaining(&self, now: DateTime<Local>) -> Duration {
+            match &self.state {
+                TimerState::Running { deadline } => (*deadline - now).max(Duration::zero()),
+                TimerState::Paused { remaining_secs } => Duration::seconds(*remaining_secs),
+                TimerState::Ringing { .. } => Duration::zero(),
+            }
+        }
+
+        /// Indique si le minuteur est en marche et a atteint son échéance.
+        ///
+        /// # Parameters
+        ///
+        /// * `now`: L'instant présent.
+        pub fn is_due(&self, now: DateTime<Local>) -> bool {
+            matches!(self.state, TimerState::Running { deadline } if deadline <= now)
+        }
+
+        /// Indique si le minuteur sonne.
+        pub fn is_ringing(&self) -> bool {
+            matches!(self.state, TimerState::Ringing { .. })
+        }
+
+        /// Met le minuteur en pause ; sans effet s'il n'est pas en marche.
+        ///
+        /// # Parameters
+        ///
+        /// * `now`: L'instant présent.
+        pub fn pause(&mut self, now: DateTime<Local>) {
+            if let TimerState::Running { .. } = self.state {
+                self.state = TimerState::Paused { remaining_secs: self.remaining(now).num_seconds() };
+            }
+        }
+
+        /// Relance le minuteur en pause ; sans effet s'il n'est pas en pause.
+        ///
+        /// # Parameters
+        ///
+        /// * `now`: L'instant présent.
+        pub fn resume(&mut self, now: DateTime<Local>) {
+            if let TimerState::Paused { remaining_secs } = self.state {
+                self.state = TimerState::Running { deadline: now + Duration::seconds(remaining_secs) };
+            }
+        }
+
+        /// Arrête le minuteur et rétablit sa durée initiale ; `resume` le relance.
+        pub fn reset(&mut self) {
+            self.state = TimerState::Paused { remaining_secs: self.duration_secs };
+        }
+
+        /// Fait sonner le minuteur arrivé à échéance.
+        ///
+        /// # Parameters
+        ///
+        /// * `now`: L'instant présent.
+        pub fn ring(&mut self, now: DateTime<Local>) {
+            self.state = TimerState::Ringing { since: now };
+        }
+
+        /// Retourne le temps restant au format `MM:SS`, ou `H:MM:SS` au-delà d'une heure.
+        ///
+        /// # Parameters
+        ///
+        /// * `now`: L'instant présent.
+        pub fn remaining_label(&self, now: DateTime<Local>) -> String {
+            // Arrondi à la seconde supérieure : un minuteur affiche 00:00 seulement quand il sonne.
+            let remaining = self.remaining(now);
+            let secs = remaining.num_seconds() + i64::from(remaining.subsec_nanos() > 0);
+            if secs >= 3600 {
+                format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+            } else {
+                format!("{:02}:{:02}", secs / 60, secs % 60)
+            }
+        }
+    }
+
+    /// Minuteurs proposés par défaut.
+    ///
+    /// # Returns
+    ///
+    /// Thé (4 min), Réunion (25 min) et Lessive (45 min).
+    pub fn default_presets() -> Vec<Preset> {
+        vec![
+            Preset { name: "Thé".to_string(), seconds: 4 * 60 },
+            Preset { name: "Réunion".to_string(), seconds: 25 * 60 },
+            Preset { name: "Lessive".to_string(), seconds: 45 * 60 },
+        ]
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use chrono::TimeZone;
+
+        fn now() -> DateTime<Local> {
+            Local.with_ymd_and_hms(2026, 10, 19, 16, 0, 0).unwrap()
+        }
+
+        #[test]
+        fn pause_resume_and_reset_keep_remaining_time() {
+            let mut tea = Timer::start(0, "Thé".to_string(), 240, now());
+            assert_eq!(tea.remaining_label(now()), "04:00");
+
+            tea.pause(now() + Duration::seconds(60));
+            // Le temps passé en pause ne compte pas.
+            assert_eq!(tea.remaining(now() + Duration::hours(1)), Duration::seconds(180));
+            tea.resume(now() + Duration::hours(1));
+            assert!(!tea.is_due(now() + Duration::hours(1) + Duration::seconds(179)));
+            assert!(tea.is_due(now() + Duration::hours(1) + Duration::seconds(180)));
+
+            tea.ring(now() + Duration::hours(1) + Duration::seconds(180));
+            assert!(tea.is_ringing());
+            assert_eq!(tea.remaining_label(now()), "00:00");
+            tea.reset();
+            assert_eq!(tea.state, TimerState::Paused { remaining_secs: 240 });
+        }
+
+        #[test]
+        fn remaining_label_rounds_up_to_the_second() {
+            let laundry = Timer::start(1, "Lessive".to_string(), 2 * 3600, now());
+            assert_eq!(laundry.remaining_label(now()), "2:00:00");
+            assert_eq!(laundry.remaining_label(now() + Duration::milliseconds(500)), "2:00:00");
+            assert_eq!(laundry.remaining_label(now() + Duration::seconds(3601)), "59:59");
+        }
+
+        #[test]
+        fn running_timer_is_saved_with_its_deadline() {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("timers.json");
+            let mut timers = vec![Timer::start(0, "Thé".to_string(), 240, now()), Timer::start(1, "Réunion".to_string(), 1500, now())];
+            timers[1].pause(now() + Duration::seconds(100));
+
+            save_timers_to(&path, &timers).unwrap();
+            let contents = fs::read_to_string(&path).unwrap();
+            let loaded = load_timers_from(&path).unwrap();
+
+            assert!(contents.contains(r#""state":"running","deadline":"#));
+            assert!(contents.contains(r#""state":"paused","remaining_secs":1400"#));
+            assert_eq!(loaded, timers);
+            assert_eq!(next_timer_id(&loaded), 2);
+        }
+    }
+}
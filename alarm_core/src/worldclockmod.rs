@@ -0,0 +1,171 @@
+pub mod worldclock {
+    use chrono::{DateTime, Timelike, TimeZone};
+    use chrono_tz::Tz;
+    use serde::{Serialize, Deserialize};
+
+    /// Heure locale à partir de laquelle il fait jour dans une ville.
+    const DAY_START_HOUR: u32 = 7;
+
+    /// Heure locale à partir de laquelle il fait nuit dans une ville.
+    const NIGHT_START_HOUR: u32 = 19;
+
+    /// Ville affichée dans l'horloge mondiale.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct City {
+        /// Nom affiché (ex. "New York").
+        pub name: String,
+        /// Fuseau horaire IANA de la ville (ex. `America/New_York`).
+        pub zone: Tz,
+    }
+
+    /// Heure courante d'une ville, comparée à l'heure du système.
+    pub struct CityTime {
+        /// L'heure dans la ville.
+        pub time: DateTime<Tz>,
+        /// Nombre de jours d'écart avec la date du système : 1 si la ville est déjà demain.
+        pub day_offset: i64,
+        /// `true` entre `DAY_START_HOUR` et `NIGHT_START_HOUR`, heure de la ville.
+        pub daytime: bool,
+    }
+
+    impl City {
+        /// Crée une nouvelle instance de `City`.
+        ///
+        /// # Parameters
+        ///
+        /// * `name`: Le nom affiché de la ville.
+        /// * `zone`: Le fuseau horaire de la ville.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `City`.
+        pub fn new(name: &str, zone: Tz) -> Self {
+            City {
+                name: name.to_string(),
+                zone,
+            }
+        }
+
+        /// Calcule l'heure de la ville à l'instant donné.
+        ///
+        /// # Parameters
+        ///
+        /// * `now`: L'instant présent, lu sur l'horloge de l'application dans le fuseau du système.
+        ///
+        /// # Returns
+        ///
+        /// L'heure de la ville, son écart en jours avec la date du système et l'indicateur jour/nuit.
+        pub fn time<Z: TimeZone>(&self, now: DateTime<Z>) -> CityTime {
+            let time = now.with_timezone(&self.zone);
+            CityTime {
+                day_offset: (time.date_naive() - now.date_naive()).num_days(),
+                daytime: (DAY_START_HOUR..NIGHT_START_HOUR).contains(&time.hour()),
+                time,
+            }
+        }
+    }
+
+    impl CityTime {
+        /// Retourne l'écart en jours sous forme lisible.
+        ///
+        /// # Returns
+        ///
+        /// "Demain", "Hier", "+2 j"..., ou une chaîne vide si la ville est au même jour que le système.
+        pub fn day_offset_label(&self) -> String {
+            match self.day_offset {
+                0 => String::new(),
+                1 => "Demain".to_string(),
+                -1 => "Hier".to_string(),
+                offset => format!("{:+} j", offset),
+            }
+        }
+
+        /// Retourne l'indicateur jour/nuit.
+        ///
+        /// # Returns
+        ///
+        /// "☀" le jour, "☾" la nuit.
+        pub fn daylight_label(&self) -> &'static str {
+            if self.daytime {
+                "☀"
+            } else {
+                "☾"
+            }
+        }
+    }
+
+    /// Villes affichées par défaut.
+    ///
+    /// # Returns
+    ///
+    /// Paris, New York et Tokyo.
+    pub fn default_cities() -> Vec<City> {
+        vec![
+            City::new("Paris", chrono_tz::Europe::Paris),
+            City::new("New York", chrono_tz::America::New_York),
+            City::new("Tokyo", chrono_tz::Asia::Tokyo),
+        ]
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use chrono::Utc;
+
+        /// Instant du 15 juin 2026 donné en UTC, vu depuis un système réglé sur `system`.
+        fn at_utc<Z: TimeZone>(system: &Z, hour: u32, minute: u32) -> DateTime<Z> {
+            Utc.with_ymd_and_hms(2026, 6, 15, hour, minute, 0).unwrap().with_timezone(system)
+        }
+
+        #[test]
+        fn city_time_follows_its_zone() {
+            let cities = default_cities();
+            // 12:00 UTC : 14:00 à Paris, 08:00 à New York, 21:00 à Tokyo.
+            let hours: Vec<u32> = cities.iter().map(|city: &City| city.time(at_utc(&Utc, 12, 0)).time.hour()).collect();
+            assert_eq!(hours, vec![14, 8, 21]);
+
+            let daylight: Vec<&str> = cities.iter().map(|city: &City| city.time(at_utc(&Utc, 12, 0)).daylight_label()).collect();
+            assert_eq!(daylight, vec!["☀", "☀", "☾"]);
+        }
+
+        #[test]
+        fn day_offset_compares_dates_with_the_system() {
+            let tokyo = City::new("Tokyo", chrono_tz::Asia::Tokyo);
+            let honolulu = City::new("Honolulu", chrono_tz::Pacific::Honolulu);
+
+            // 23:30 UTC : le 16 juin 08:30 à Tokyo, le 15 juin 13:30 à Honolulu.
+            // Système en UTC, le 15 juin : Tokyo est déjà demain.
+            let now = at_utc(&Utc, 23, 30);
+            let tokyo_time = tokyo.time(now);
+            assert_eq!(tokyo_time.time.format("%d %H:%M").to_string(), "16 08:30");
+            assert_eq!(tokyo_time.day_offset, 1);
+            assert_eq!(tokyo_time.day_offset_label(), "Demain");
+            let honolulu_time = honolulu.time(now);
+            assert_eq!(honolulu_time.time.format("%d %H:%M").to_string(), "15 13:30");
+            assert_eq!(honolulu_time.day_offset, 0);
+            assert_eq!(honolulu_time.day_offset_label(), "");
+
+            // Système à Paris, déjà le 16 juin à 01:30 : Honolulu est encore hier.
+            let now = at_utc(&chrono_tz::Europe::Paris, 23, 30);
+            assert_eq!(tokyo.time(now).day_offset, 0);
+            assert_eq!(honolulu.time(now).day_offset, -1);
+            assert_eq!(honolulu.time(now).day_offset_label(), "Hier");
+
+            // Système à Honolulu, le 15 juin : Kiritimati (UTC+14) est le 16 juin 13:30.
+            let kiritimati = City::new("Kiritimati", chrono_tz::Pacific::Kiritimati);
+            let now = at_utc(&chrono_tz::Pacific::Honolulu, 23, 30);
+            assert_eq!(kiritimati.time(now).day_offset, 1);
+            let label = |day_offset: i64| CityTime { day_offset, ..tokyo.time(now) }.day_offset_label();
+            assert_eq!(label(2), "+2 j");
+            assert_eq!(label(-2), "-2 j");
+        }
+
+        #[test]
+        fn city_round_trips_through_json() {
+            let city = City::new("New York", chrono_tz::America::New_York);
+            let json = serde_json::to_string(&city).unwrap();
+            assert_eq!(json, r#"{"name":"New York","zone":"America/New_York"}"#);
+            assert_eq!(serde_json::from_str::<City>(&json).unwrap(), city);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+//! Tests du modèle des alarmes : comparaison d'horaires, récurrence, sérialisation et sauvegarde.
+
+use alarm_core::modelmod::model::{self, AlarmClock, AmbiguousTime, DstPolicy, Horaire, NonexistentTime, RadioStation};
+use chrono::{DateTime, Datelike, Duration, Local, TimeZone, Timelike, Utc, Weekday};
+use chrono_tz::America::New_York;
+use chrono_tz::Europe::Paris;
+use std::fs;
+
+const WEEKDAYS: [bool; 7] = [true, true, true, true, true, false, false];
+
+fn alarm(a_id: usize, hour: u8, minute: u8, days: [bool; 7]) -> AlarmClock {
+    AlarmClock::new(a_id, format!("Alarme {}", a_id), hour, minute, 0, true, Some(RadioStation::FranceInter), days)
+}
+
+/// Lundi 15 juin 2026 à minuit, loin des changements d'heure.
+fn monday() -> DateTime<Local> {
+    Local.with_ymd_and_hms(2026, 6, 15, 0, 0, 0).unwrap()
+}
+
+fn at(days: i64, hour: i64, minute: i64) -> DateTime<Local> {
+    monday() + Duration::days(days) + Duration::hours(hour) + Duration::minutes(minute)
+}
+
+#[test]
+fn to_compare_matches_time_and_day() {
+    let alarm = alarm(0, 7, 30, WEEKDAYS);
+    let horaire = |hour: u8, minute: u8, second: u8| Horaire { hour, minute, second };
+
+    assert!(alarm.to_compare(&horaire(7, 30, 0), 0));
+    assert!(alarm.to_compare(&horaire(7, 30, 0), 4));
+    assert!(!alarm.to_compare(&horaire(7, 30, 0), 5));
+    assert!(!alarm.to_compare(&horaire(7, 30, 1), 0));
+    assert!(!alarm.to_compare(&horaire(7, 31, 0), 0));
+    assert!(!alarm.to_compare(&horaire(8, 30, 0), 0));
+}
+
+#[test]
+fn next_occurrence_skips_unselected_days() {
+    let alarm = alarm(0, 7, 0, WEEKDAYS);
+
+    assert_eq!(alarm.next_occurrence(at(0, 6, 0)), Some(at(0, 7, 0)));
+    // L'occurrence doit être strictement postérieure à l'instant donné.
+    assert_eq!(alarm.next_occurrence(at(0, 7, 0)), Some(at(1, 7, 0)));
+    // Vendredi soir : prochaine occurrence le lundi suivant.
+    assert_eq!(alarm.next_occurrence(at(4, 8, 0)), Some(at(7, 7, 0)));
+}
+
+#[test]
+fn next_occurrence_on_a_single_day_waits_a_week() {
+    let mut days = [false; 7];
+    days[0] = true;
+    let alarm = alarm(0, 7, 0, days);
+
+    assert_eq!(alarm.next_occurrence(at(0, 7, 0)), Some(at(7, 7, 0)));
+}
+
+#[test]
+fn alarm_without_days_never_occurs() {
+    let alarm = alarm(0, 7, 0, [false; 7]);
+
+    assert_eq!(alarm.next_occurrence(monday()), None);
+    assert_eq!(alarm.occurs_between(monday(), at(30, 0, 0)), None);
+}
+
+#[test]
+fn occurs_between_excludes_start_and_includes_end() {
+    let alarm = alarm(0, 7, 0, [true; 7]);
+
+    assert_eq!(alarm.occurs_between(at(0, 6, 59), at(0, 7, 0)), Some(at(0, 7, 0)));
+    assert_eq!(alarm.occurs_between(at(0, 7, 0), at(0, 7, 1)), None);
+    assert_eq!(alarm.occurs_between(at(0, 6, 0), at(0, 6, 59)), None);
+}
+
+#[test]
+fn next_alarm_picks_the_earliest_active_alarm() {
+    let mut early = alarm(1, 6, 0, [true; 7]);
+    early.active = false;
+    let alarms = vec![alarm(0, 8, 0, [true; 7]), early, alarm(2, 7, 0, [true; 7])];
+
+    let (next, when) = model::next_alarm(&alarms, monday()).unwrap();
+    assert_eq!(next.a_id, 2);
+    assert_eq!(when, at(0, 7, 0));
+}
+
+/// Alarme quotidienne à l'heure donnée, à Paris.
+fn paris_alarm(hour: u8, minute: u8, dst: DstPolicy) -> AlarmClock {
+    let mut alarm = alarm(0, hour, minute, [true; 7]);
+    alarm.time_zone = Some(Paris);
+    alarm.dst = dst;
+    alarm
+}
+
+fn utc(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+    Utc.with_ymd_and_hms(2026, month, day, hour, minute, 0).unwrap().with_timezone(&Local)
+}
+
+#[test]
+fn time_zone_alarm_ignores_system_zone() {
+    let mut alarm = alarm(0, 9, 0, [true; 7]);
+    alarm.time_zone = Some(New_York);
+
+    // 09:00 à New York en juin : 13:00 UTC, quel que soit le fuseau du système.
+    assert_eq!(alarm.next_occurrence(utc(6, 15, 12, 0)), Some(utc(6, 15, 13, 0)));
+    assert_eq!(alarm.scheduled_label(), "09:00:00 America/New_York");
+}
+
+#[test]
+fn time_zone_alarm_days_are_those_of_its_zone() {
+    let mut days = [false; 7];
+    days[0] = true;
+    let mut alarm = alarm(0, 23, 30, days);
+    alarm.time_zone = Some(New_York);
+
+    // Le lundi 23:30 à New York est déjà mardi en UTC.
+    let next = alarm.next_occurrence(utc(6, 15, 0, 0)).unwrap();
+    assert_eq!(next, utc(6, 16, 3, 30));
+    assert_eq!(next.with_timezone(&New_York).weekday(), Weekday::Mon);
+}
+
+#[test]
+fn nonexistent_time_is_shifted_by_default() {
+    // Le 29 mars 2026, Paris passe de 02:00 à 03:00 : 02:30 n'existe pas.
+    let alarm = paris_alarm(2, 30, DstPolicy::default());
+    let next = alarm.next_occurrence(utc(3, 28, 23, 0)).unwrap();
+
+    assert_eq!(next, utc(3, 29, 1, 30));
+    assert_eq!(next.with_timezone(&Paris).hour(), 3);
+    assert_eq!(next.with_timezone(&Paris).minute(), 30);
+    // Le lendemain, l'alarme retrouve son heure.
+    assert_eq!(alarm.next_occurrence(next), Some(utc(3, 30, 0, 30)));
+}
+
+#[test]
+fn nonexistent_time_can_be_skipped() {
+    let alarm = paris_alarm(2, 30, DstPolicy { nonexistent: NonexistentTime::Skip, ..DstPolicy::default() });
+
+    assert_eq!(alarm.next_occurrence(utc(3, 28, 23, 0)), Some(utc(3, 30, 0, 30)));
+}
+
+#[test]
+fn ambiguous_time_occurs_once() {
+    // Le 25 octobre 2026, Paris passe de 03:00 à 02:00 : 02:30 existe deux fois.
+    let earliest = paris_alarm(2, 30, DstPolicy::default());
+    let latest = paris_alarm(2, 30, DstPolicy { ambiguous: AmbiguousTime::Latest, ..DstPolicy::default() });
+    let before = utc(10, 24, 22, 0);
+
+    assert_eq!(earliest.next_occurrence(before), Some(utc(10, 25, 0, 30)));
+    assert_eq!(latest.next_occurrence(before), Some(utc(10, 25, 1, 30)));
+    // La seconde 02:30 ne déclenche pas de nouveau l'alarme réglée sur la première.
+    assert_eq!(earliest.next_occurrence(utc(10, 25, 0, 30)), Some(utc(10, 26, 1, 30)));
+    assert_eq!(latest.next_occurrence(utc(10, 25, 1, 30)), Some(utc(10, 26, 1, 30)));
+}
+
+#[test]
+fn time_zone_and_dst_policy_round_trip_through_json() {
+    let alarm = paris_alarm(2, 30, DstPolicy { nonexistent: NonexistentTime::Skip, ambiguous: AmbiguousTime::Latest });
+    let json = serde_json::to_string(&alarm).unwrap();
+
+    assert!(json.contains(r#""time_zone":"Europe/Paris""#));
+    assert!(json.contains(r#""dst":{"nonexistent":"skip","ambiguous":"latest"}"#));
+    assert_eq!(serde_json::from_str::<AlarmClock>(&json).unwrap(), alarm);
+
+    let unknown = json.replace("Europe/Paris", "Europe/Atlantis");
+    assert!(model::parse_alarms(&format!("[{}]", unknown)).is_err());
+}
+
+#[test]
+fn alarm_clock_round_trips_through_json() {
+    let mut radio = alarm(3, 6, 45, WEEKDAYS);
+    radio.hooks.pre_alarm = "echo chauffage".to_string();
+    radio.hooks.pre_alarm_minutes = 15;
+    radio.other_players.resume = true;
+    let mut song = AlarmClock::new(4, "Chanson".to_string(), 9, 0, 30, false, None, [true; 7]);
+    song.song_path = "song/Alarm_4.wav".to_string();
+    song.song_title = "Titre".to_string();
+    song.active = false;
+
+    for alarm in [radio, song] {
+        let json = serde_json::to_string(&alarm).unwrap();
+        assert_eq!(serde_json::from_str::<AlarmClock>(&json).unwrap(), alarm);
+    }
+}
+
+#[test]
+fn horaire_and_radio_station_round_trip_through_json() {
+    let horaire = Horaire { hour: 23, minute: 59, second: 59 };
+    let json = serde_json::to_string(&horaire).unwrap();
+    assert_eq!(serde_json::from_str::<Horaire>(&json).unwrap(), horaire);
+
+    for station in RadioStation::ALL {
+        let json = serde_json::to_string(&station).unwrap();
+        assert_eq!(serde_json::from_str::<RadioStation>(&json).unwrap(), station);
+    }
+}
+
+#[test]
+fn alarm_file_without_hooks_still_loads() {
+    // Format des sauvegardes antérieures aux commandes et aux lecteurs MPRIS.
+    let json = r#"[{"horaire":{"hour":7,"minute":0,"second":0},"active":true,"is_radio":true,
+        "song_path":"","song_title":"","a_radio":"RTL","a_id":0,"name":"Réveil",
+        "days":[true,true,true,true,true,false,false]}]"#;
+
+    let alarms = model::parse_alarms(json).unwrap();
+    assert_eq!(alarms.len(), 1);
+    assert_eq!(alarms[0].a_radio, Some(RadioStation::RTL));
+    assert!(alarms[0].hooks.pre_alarm.is_empty());
+    assert_eq!(alarms[0].time_zone, None);
+    assert_eq!(alarms[0].dst, DstPolicy::default());
+}
+
+#[test]
+fn save_then_load_restores_alarms() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("alarms.json");
+    let alarms = vec![alarm(0, 7, 0, WEEKDAYS), alarm(5, 9, 30, [true; 7])];
+
+    model::save_alarms_to(&path, &alarms).unwrap();
+
+    assert_eq!(model::load_alarms_from(&path).unwrap(), alarms);
+}
+
+#[test]
+fn missing_or_empty_file_loads_no_alarm() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("alarms.json");
+    assert!(model::load_alarms_from(&path).unwrap().is_empty());
+
+    fs::write(&path, "").unwrap();
+    assert!(model::load_alarms_from(&path).unwrap().is_empty());
+
+    fs::write(&path, "  \n").unwrap();
+    assert!(model::load_alarms_from(&path).unwrap().is_empty());
+
+    fs::write(&path, "[]").unwrap();
+    assert!(model::load_alarms_from(&path).unwrap().is_empty());
+}
+
+#[test]
+fn corrupt_file_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("alarms.json");
+
+    for contents in ["{", "[{\"a_id\":0}]", "not json", "[{\"horaire\":{\"hour\":300,\"minute\":0,\"second\":0}}]"] {
+        fs::write(&path, contents).unwrap();
+        assert!(model::load_alarms_from(&path).is_err(), "{contents:?} should not load");
+    }
+}
+
+#[test]
+fn ids_are_stable_across_deletes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("alarms.json");
+    let mut alarms: Vec<AlarmClock> = Vec::new();
+    for hour in 6..10 {
+        let id = model::next_alarm_id_from(&path, &alarms);
+        alarms.push(alarm(id, hour, 0, WEEKDAYS));
+    }
+    assert_eq!(alarms.iter().map(|alarm: &AlarmClock| alarm.a_id).collect::<Vec<usize>>(), vec![0, 1, 2, 3]);
+
+    // Supprimer une alarme ne renumérote pas les autres, y compris après sauvegarde.
+    alarms.retain(|alarm: &AlarmClock| alarm.a_id != 1);
+    model::save_alarms_to(&path, &alarms).unwrap();
+    let mut alarms = model::load_alarms_from(&path).unwrap();
+    assert_eq!(alarms.iter().map(|alarm: &AlarmClock| alarm.a_id).collect::<Vec<usize>>(), vec![0, 2, 3]);
+    assert_eq!(alarms[1].horaire.hour, 8);
+
+    // Un trou laissé par une suppression n'est pas comblé.
+    let id = model::next_alarm_id_from(&path, &alarms);
+    assert_eq!(id, 4);
+    alarms.push(alarm(id, 10, 0, WEEKDAYS));
+    alarms.retain(|alarm: &AlarmClock| alarm.a_id != 0);
+    assert_eq!(model::next_alarm_id_from(&path, &alarms), 5);
+
+    // Supprimer l'alarme au plus grand identifiant ne le libère pas, même après rechargement.
+    model::save_alarms_to(&path, &alarms).unwrap();
+    alarms.retain(|alarm: &AlarmClock| alarm.a_id != 4);
+    model::save_alarms_to(&path, &alarms).unwrap();
+    let alarms = model::load_alarms_from(&path).unwrap();
+    assert_eq!(alarms.iter().map(|alarm: &AlarmClock| alarm.a_id).collect::<Vec<usize>>(), vec![2, 3]);
+    assert_eq!(model::next_alarm_id_from(&path, &alarms), 5);
+
+    // Même une fois toutes les alarmes supprimées.
+    model::save_alarms_to(&path, &[]).unwrap();
+    assert_eq!(model::next_alarm_id_from(&path, &[]), 5);
+}
+
+#[test]
+fn parses_times_with_or_without_seconds() {
+    assert_eq!(model::parse_time("07:30"), Ok((7, 30, 0)));
+    assert_eq!(model::parse_time("23:59:59"), Ok((23, 59, 59)));
+    for invalid in ["7", "24:00", "07:60", "07:30:60", "07:30:00:00", "sept:30"] {
+        assert!(model::parse_time(invalid).is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn parses_day_lists_and_ranges() {
+    assert_eq!(model::parse_days("mon-fri"), Ok([true, true, true, true, true, false, false]));
+    assert_eq!(model::parse_days("sat,sun"), Ok([false, false, false, false, false, true, true]));
+    assert_eq!(model::parse_days("lun,mer"), Ok([true, false, true, false, false, false, false]));
+    assert_eq!(model::parse_days("fri-mon"), Ok([true, false, false, false, true, true, true]));
+    assert_eq!(model::parse_days("all"), Ok([true; 7]));
+    assert!(model::parse_days("mon-xyz").is_err());
+}
+
+#[test]
+fn dated_alarm_occurs_only_on_its_date() {
+    let mut alarm = alarm(0, 7, 0, [false; 7]);
+    alarm.date = Some(monday().date_naive() + Duration::days(2));
+    assert_eq!(alarm.next_occurrence(monday()), Some(at(2, 7, 0)));
+    assert_eq!(alarm.next_occurrence(at(2, 7, 0)), None);
+}
+
+#[test]
+fn passed_dated_alarms_are_disabled() {
+    let mut dated = alarm(0, 7, 0, [true; 7]);
+    dated.date = Some(monday().date_naive());
+    let mut alarms = vec![dated.clone(), alarm(1, 7, 0, [true; 7])];
+    assert!(!model::disable_expired(&mut alarms, at(0, 6, 0)));
+    assert!(model::disable_expired(&mut alarms, at(0, 7, 0)));
+    assert!(!alarms[0].active);
+    assert!(alarms[1].active);
+}
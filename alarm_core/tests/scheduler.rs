@@ -0,0 +1,170 @@
+//! Propriétés du planificateur : chaque occurrence programmée sonne une seule fois, ou est
+//! signalée manquée, quel que soit l'écart entre deux vérifications et même si plusieurs
+//! alarmes sont dues en même temps.
+
+use alarm_core::clockmod::clock::{Clock, FakeClock};
+use alarm_core::historymod::history::{EventKind, History, HistoryEntry};
+use alarm_core::modelmod::model::{AlarmClock, AmbiguousTime, DstPolicy, NonexistentTime, RadioStation};
+use alarm_core::schedulermod::scheduler::Scheduler;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveTime, TimeZone, Utc};
+use chrono_tz::Europe::Paris;
+use proptest::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Écart maximal toléré avant qu'une alarme soit manquée, en secondes (`MISSED_AFTER_SECS`).
+const MAX_JITTER_SECS: i64 = 60;
+
+/// Lundi 15 juin 2026 à minuit.
+fn start() -> DateTime<Local> {
+    Local.with_ymd_and_hms(2026, 6, 15, 0, 0, 0).unwrap()
+}
+
+/// Compte les occurrences de l'alarme comprises entre `after` (exclu) et `until` (inclus),
+/// jour par jour, sans passer par le planificateur.
+fn expected_occurrences(alarm: &AlarmClock, after: DateTime<Local>, until: DateTime<Local>) -> usize {
+    let time = NaiveTime::from_hms_opt(alarm.horaire.hour as u32, alarm.horaire.minute as u32, alarm.horaire.second as u32).unwrap();
+    let mut count = 0;
+    let mut date = after.date_naive();
+    while date <= until.date_naive() {
+        if alarm.days[date.weekday().num_days_from_monday() as usize] {
+            if let Some(at) = Local.from_local_datetime(&date.and_time(time)).earliest() {
+                if at > after && at <= until {
+                    count += 1;
+                }
+            }
+        }
+        date = date.succ_opt().unwrap();
+    }
+    count
+}
+
+/// Alarmes aux horaires souvent identiques ou à quelques secondes d'écart, pour que plusieurs
+/// soient dues à la même vérification.
+fn alarms() -> impl Strategy<Value = Vec<AlarmClock>> {
+    let time = (
+        proptest::sample::select(vec![6u8, 7]),
+        proptest::sample::select(vec![0u8, 1, 30]),
+        proptest::sample::select(vec![0u8, 1, 20, 59]),
+    );
+    proptest::collection::vec((time, any::<[bool; 7]>()), 1..6).prop_map(|settings| {
+        settings.into_iter().enumerate()
+            .map(|(a_id, ((hour, minute, second), days))| {
+                AlarmClock::new(a_id, format!("Alarme {}", a_id), hour, minute, second, true, Some(RadioStation::RTL), days)
+            })
+            .collect()
+    })
+}
+
+/// Déclenchements et alarmes manquées, par alarme.
+#[derive(Default)]
+struct Outcome {
+    fired: HashMap<usize, usize>,
+    missed: HashMap<usize, usize>,
+}
+
+impl Outcome {
+    fn count(&self, a_id: usize) -> usize {
+        self.fired.get(&a_id).copied().unwrap_or(0) + self.missed.get(&a_id).copied().unwrap_or(0)
+    }
+}
+
+/// Fait tourner le planificateur avec les écarts donnés, répétés jusqu'à couvrir `days` jours,
+/// puis vérifie chaque seconde jusqu'à ce que les alarmes en attente aient sonné.
+///
+/// Chaque alarme déclenchée est arrêtée aussitôt.
+fn run(alarms: Vec<AlarmClock>, steps: &[i64], days: i64) -> (Scheduler, Outcome, DateTime<Local>) {
+    let clock = FakeClock::new(start());
+    let mut scheduler = Scheduler::new(History::default(), Arc::new(clock.clone()));
+    scheduler.set_alarms(alarms);
+    let end = start() + Duration::days(days);
+    let mut outcome = Outcome::default();
+    scheduler.tick();
+    let mut steps = steps.iter().cycle();
+    loop {
+        let step = if clock.now() < end { *steps.next().unwrap() } else { 1 };
+        clock.advance(Duration::seconds(step));
+        let tick = scheduler.tick();
+        for alarm in tick.missed.iter() {
+            *outcome.missed.entry(alarm.a_id).or_default() += 1;
+        }
+        match tick.fired {
+            Some(alarm) => {
+                *outcome.fired.entry(alarm.a_id).or_default() += 1;
+                scheduler.fired(alarm);
+                scheduler.dismiss();
+            }
+            None if clock.now() >= end => break,
+            None => {}
+        }
+    }
+    let now = clock.now();
+    (scheduler, outcome, now)
+}
+
+/// Compte les déclenchements d'une alarme quotidienne à 02:30 à Paris, autour du jour de changement d'heure donné.
+fn fires_around_dst_change(month: u32, day: u32, dst: DstPolicy) -> Vec<DateTime<Local>> {
+    let mut alarm = AlarmClock::new(0, "Nuit".to_string(), 2, 30, 0, true, Some(RadioStation::RTL), [true; 7]);
+    alarm.time_zone = Some(Paris);
+    alarm.dst = dst;
+    let start = Utc.with_ymd_and_hms(2026, month, day, 12, 0, 0).unwrap().with_timezone(&Local) - Duration::days(1);
+    let clock = FakeClock::new(start);
+    let mut scheduler = Scheduler::new(History::default(), Arc::new(clock.clone()));
+    scheduler.set_alarms(vec![alarm]);
+    let mut fired = Vec::new();
+    scheduler.tick();
+    while clock.now() < start + Duration::days(2) {
+        clock.advance(Duration::seconds(10));
+        if scheduler.tick().fired.is_some() {
+            fired.push(clock.now());
+        }
+    }
+    fired
+}
+
+#[test]
+fn dst_change_days_fire_once_per_policy() {
+    let utc = |month: u32, day: u32, hour: u32, minute: u32| Utc.with_ymd_and_hms(2026, month, day, hour, minute, 0).unwrap().with_timezone(&Local);
+    let skip = DstPolicy { nonexistent: NonexistentTime::Skip, ..DstPolicy::default() };
+    let latest = DstPolicy { ambiguous: AmbiguousTime::Latest, ..DstPolicy::default() };
+
+    // Passage à l'heure d'été le 29 mars : 02:30 n'existe pas.
+    assert_eq!(fires_around_dst_change(3, 29, DstPolicy::default()), vec![utc(3, 29, 1, 30), utc(3, 30, 0, 30)]);
+    assert_eq!(fires_around_dst_change(3, 29, skip), vec![utc(3, 30, 0, 30)]);
+    // Passage à l'heure d'hiver le 25 octobre : 02:30 existe deux fois.
+    assert_eq!(fires_around_dst_change(10, 25, DstPolicy::default()), vec![utc(10, 25, 0, 30), utc(10, 26, 1, 30)]);
+    assert_eq!(fires_around_dst_change(10, 25, latest), vec![utc(10, 25, 1, 30), utc(10, 26, 1, 30)]);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn alarms_fire_once_per_occurrence_under_jitter(
+        alarms in alarms(),
+        steps in proptest::collection::vec(1..=MAX_JITTER_SECS, 1..50),
+    ) {
+        let (scheduler, outcome, end) = run(alarms.clone(), &steps, 8);
+
+        prop_assert!(outcome.missed.is_empty(), "no alarm should be missed with steps under {} s", MAX_JITTER_SECS);
+        for alarm in alarms.iter() {
+            let expected = expected_occurrences(alarm, start(), end);
+            prop_assert_eq!(outcome.count(alarm.a_id), expected, "alarm {}", alarm.a_id);
+        }
+        let triggered = scheduler.history().entries.iter().filter(|entry: &&HistoryEntry| entry.kind == EventKind::Triggered).count();
+        prop_assert_eq!(triggered, outcome.fired.values().sum::<usize>());
+    }
+
+    #[test]
+    fn long_gaps_report_each_occurrence_once(
+        alarms in alarms(),
+        steps in proptest::collection::vec(1..=600i64, 1..50),
+    ) {
+        let (_, outcome, end) = run(alarms.clone(), &steps, 8);
+
+        // Une vérification tardive manque l'alarme au lieu de la faire sonner, mais ne la perd ni ne la double.
+        for alarm in alarms.iter() {
+            prop_assert_eq!(outcome.count(alarm.a_id), expected_occurrences(alarm, start(), end), "alarm {}", alarm.a_id);
+        }
+    }
+}
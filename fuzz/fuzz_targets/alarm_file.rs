@@ -0,0 +1,30 @@
+#![no_main]
+
+//! Fuzzing du lecteur du fichier des alarmes (`ser/alarms.json`).
+//!
+//! Un fichier corrompu doit produire une erreur, jamais une panique, et les alarmes lues
+//! doivent pouvoir être planifiées et sauvegardées de nouveau à l'identique.
+
+use alarm_core::modelmod::model::{self, AlarmClock};
+use chrono::{Local, TimeZone};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(contents) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(alarms) = model::parse_alarms(contents) else {
+        return;
+    };
+
+    let now = Local.with_ymd_and_hms(2026, 6, 15, 7, 0, 0).unwrap();
+    let _ = model::next_alarm(&alarms, now);
+    let _ = model::next_alarm_id(&alarms);
+    for alarm in alarms.iter() {
+        let _ = alarm.occurs_between(now, now + chrono::Duration::days(7));
+    }
+
+    let serialized = serde_json::to_string(&alarms).unwrap();
+    let reparsed: Vec<AlarmClock> = model::parse_alarms(&serialized).unwrap();
+    assert_eq!(reparsed, alarms);
+});
@@ -0,0 +1,401 @@
+pub mod cli {
+    use chrono::{DateTime, Local, NaiveDateTime};
+    use serde::Serialize;
+    use serde_json::json;
+    use crate::controlmod::control::{self, Command, NewAlarm};
+    use crate::downloadmod::download;
+    use crate::modelmod::model::{self, AlarmClock, AlarmHooks, AmbiguousTime, DstPolicy, NonexistentTime, OtherPlayers, RadioStation, DAY_NAMES};
+
+    /// Sous-commandes reconnues en ligne de commande.
+    pub const SUBCOMMANDS: [&str; 8] = ["list", "add", "remove", "enable", "disable", "next", "play-radio", "stop"];
+
+    /// Accélération maximale de la simulation : au-delà, une vérification par centième de seconde
+    /// sauterait plus d'une minute et les alarmes seraient comptées comme manquées.
+    const MAX_SPEED: f64 = 3600.0;
+
+    const USAGE: &str = "Usage: rust_first <commande> [--json]
+
+Commandes :
+  list                                    Liste les alarmes
+  add --time HH:MM[:SS] [--days mon-fri] [--radio NOM | --url LIEN] [--name NOM]
+      [--pre-alarm CMD --pre-alarm-minutes N] [--on-fire CMD] [--on-dismiss CMD]
+      [--no-pause-players] [--resume-players]
+      [--tz Europe/Paris] [--dst-nonexistent shift|skip] [--dst-ambiguous earliest|latest]
+      [--date AAAA-MM-JJ]
+                                          Ajoute une alarme
+  remove <id>                             Supprime une alarme
+  enable <id> | disable <id>              Active ou désactive une alarme
+  next                                    Affiche la prochaine alarme
+  play-radio <station>                    Lance une station sur l'instance en cours
+  stop                                    Arrête la lecture sur l'instance en cours
+
+Options transmises à la fenêtre déjà ouverte :
+  --play-radio <station> | --stop | --snooze | --add --time HH:MM ...
+
+Options de lancement :
+  --headless                              Sans interface graphique
+  --simulate AAAA-MM-JJTHH:MM[:SS] [--speed N] [--null-audio]
+                                          Simule le temps depuis l'instant donné, N fois plus vite";
+
+    /// Simulation du temps demandée avec `--simulate`.
+    pub struct Simulation {
+        /// Instant virtuel de départ.
+        pub start: DateTime<Local>,
+        /// Nombre de secondes virtuelles par seconde réelle.
+        pub speed: f64,
+        /// Envoie le son au moteur `null` plutôt qu'à la carte son.
+        pub null_audio: bool,
+    }
+
+    /// Prochain déclenchement d'une alarme, tel qu'affiché par `next`.
+    #[derive(Serialize)]
+    struct NextAlarm {
+        id: usize,
+        name: String,
+        at: DateTime<Local>,
+    }
+
+    /// Indique si les arguments correspondent à une sous-commande de la ligne de commande.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - Arguments du programme, sans le nom de l'exécutable.
+    pub fn is_command(args: &[String]) -> bool {
+        args.first().map(|arg: &String| SUBCOMMANDS.contains(&arg.as_str())).unwrap_or(false)
+    }
+
+    /// Exécute une sous-commande sur le fichier d'alarmes partagé avec l'interface graphique.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - Arguments du programme, sans le nom de l'exécutable.
+    ///
+    /// # Returns
+    ///
+    /// Le code de sortie du processus.
+    pub fn run(args: &[String]) -> i32 {
+        let json_output = args.iter().any(|arg: &String| arg == "--json");
+        let args: Vec<String> = args.iter().filter(|arg: &&String| *arg != "--json").cloned().collect();
+        let result = match args.first().map(String::as_str) {
+            Some("list") => list(json_output),
+            Some("add") => add(&args[1..], json_output),
+            Some("remove") => remove(&args[1..]),
+            Some("enable") => set_active(&args[1..], true),
+            Some("disable") => set_active(&args[1..], false),
+            Some("next") => next(json_output),
+            Some("play-radio") => play_radio(&args[1..]),
+            Some("stop") => control::queue(Command::Stop).map_err(|error| format!("Failed to send command {error:?}")),
+            _ => Err(USAGE.to_string()),
+        };
+        match result {
+            Ok(_) => 0,
+            Err(message) => {
+                eprintln!("[ERROR] {}", message);
+                1
+            }
+        }
+    }
+
+    /// Charge les alarmes sauvegardées.
+    fn load() -> Result<Vec<AlarmClock>, String> {
+        model::load_alarms().map_err(|error| format!("Failed to load alarms {error:?}"))
+    }
+
+    /// Sauvegarde les alarmes ; l'instance en cours les recharge, ou les fusionne avec ses propres changements.
+    fn save(alarms: &[AlarmClock]) -> Result<(), String> {
+        model::save_alarms(alarms).map_err(|error| format!("Failed to save alarms {error:?}"))
+    }
+
+    /// Formate une alarme sur une ligne.
+    fn describe(alarm: &AlarmClock) -> String {
+        let days = match alarm.date {
+            Some(date) => date.format("%d/%m/%Y").to_string(),
+            None => DAY_NAMES.iter()
+                .zip(alarm.days.iter())
+                .filter(|(_, active)| **active)
+                .map(|(day, _)| *day)
+                .collect::<Vec<&str>>()
+                .join(" "),
+        };
+        format!(
+            "#{:<3} {:<8}  {:<28} {:<20} {:<8} {}",
+            alarm.a_id,
+            alarm.scheduled_label(),
+            days,
+            alarm.source_label(),
+            if alarm.active { "active" } else { "inactive" },
+            alarm.name,
+        )
+    }
+
+    /// Sous-commande `list`.
+    fn list(json_output: bool) -> Result<(), String> {
+        let alarms = load()?;
+        if json_output {
+            println!("{}", serde_json::to_string_pretty(&alarms).map_err(|error| error.to_string())?);
+        } else if alarms.is_empty() {
+            println!("Aucune alarme");
+        } else {
+            for alarm in alarms.iter() {
+                println!("{}", describe(alarm));
+            }
+        }
+        Ok(())
+    }
+
+    /// Retourne la valeur d'une option `--nom valeur`.
+    fn option<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+        args.iter()
+            .position(|arg: &String| arg == name)
+            .and_then(|index| args.get(index + 1))
+            .map(String::as_str)
+    }
+
+    /// Analyse les règles de changement d'heure (`--dst-nonexistent shift|skip`, `--dst-ambiguous earliest|latest`).
+    ///
+    /// # Returns
+    ///
+    /// Les règles choisies, par défaut pour une option absente.
+    fn parse_dst(args: &[String]) -> Result<DstPolicy, String> {
+        let nonexistent = match option(args, "--dst-nonexistent") {
+            None | Some("shift") => NonexistentTime::Shift,
+            Some("skip") => NonexistentTime::Skip,
+            Some(value) => return Err(format!("Invalid --dst-nonexistent {}, expected shift or skip", value)),
+        };
+        let ambiguous = match option(args, "--dst-ambiguous") {
+            None | Some("earliest") => AmbiguousTime::Earliest,
+            Some("latest") => AmbiguousTime::Latest,
+            Some(value) => return Err(format!("Invalid --dst-ambiguous {}, expected earliest or latest", value)),
+        };
+        Ok(DstPolicy { nonexistent, ambiguous })
+    }
+
+    /// Analyse l'identifiant d'alarme passé en argument.
+    fn parse_id(args: &[String]) -> Result<usize, String> {
+        args.first()
+            .ok_or_else(|| "Missing alarm id".to_string())?
+            .parse()
+            .map_err(|_| "Invalid alarm id".to_string())
+    }
+
+    /// Analyse les options d'une alarme à créer (`--time`, `--days`, `--radio`, `--url`...).
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - Options de la sous-commande `add`.
+    ///
+    /// # Returns
+    ///
+    /// Les paramètres de l'alarme, ou un message d'erreur si une option est invalide.
+    pub fn parse_new_alarm(args: &[String]) -> Result<NewAlarm, String> {
+        Ok(NewAlarm {
+            time: option(args, "--time").ok_or("Missing --time")?.to_string(),
+            days: option(args, "--days").map(str::to_string),
+            radio: option(args, "--radio").map(str::to_string),
+            url: option(args, "--url").map(str::to_string),
+            name: option(args, "--name").map(str::to_string),
+            hooks: AlarmHooks {
+                pre_alarm: option(args, "--pre-alarm").unwrap_or_default().to_string(),
+                pre_alarm_minutes: match option(args, "--pre-alarm-minutes") {
+                    Some(minutes) => minutes.parse().map_err(|_| format!("Invalid minutes {}", minutes))?,
+                    None => 0,
+                },
+                on_fire: option(args, "--on-fire").unwrap_or_default().to_string(),
+                on_dismiss: option(args, "--on-dismiss").unwrap_or_default().to_string(),
+            },
+            other_players: OtherPlayers {
+                pause: !args.iter().any(|arg: &String| arg == "--no-pause-players"),
+                resume: args.iter().any(|arg: &String| arg == "--resume-players"),
+            },
+            time_zone: option(args, "--tz").map(str::to_string),
+            dst: parse_dst(args)?,
+            date: option(args, "--date").map(str::to_string),
+        })
+    }
+
+    /// Traduit les options transmises à l'instance déjà lancée en commandes.
+    ///
+    /// Options reconnues : `--play-radio <station>`, `--stop`, `--snooze`,
+    /// et `--add` suivi des options de la sous-commande `add`.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - Arguments du programme, sans le nom de l'exécutable.
+    ///
+    /// # Returns
+    ///
+    /// Les commandes à exécuter, vide si aucune option n'est reconnue.
+    pub fn forwarded_commands(args: &[String]) -> Result<Vec<Command>, String> {
+        let mut commands = Vec::new();
+        if let Some(index) = args.iter().position(|arg: &String| arg == "--add") {
+            commands.push(Command::AddAlarm(Box::new(parse_new_alarm(&args[index + 1..])?)));
+        }
+        if args.iter().any(|arg: &String| arg == "--play-radio") {
+            let station = option(args, "--play-radio").ok_or("Missing station after --play-radio")?;
+            commands.push(Command::PlayRadio { station: station.to_string() });
+        }
+        if args.iter().any(|arg: &String| arg == "--snooze") {
+            commands.push(Command::Snooze);
+        }
+        if args.iter().any(|arg: &String| arg == "--stop") {
+            commands.push(Command::Stop);
+        }
+        Ok(commands)
+    }
+
+    /// Lit les options de simulation : `--simulate AAAA-MM-JJTHH:MM[:SS]`, `--speed N` (1 par défaut) et `--null-audio`.
+    ///
+    /// # Parameters
+    ///
+    /// * `args` - Arguments du programme, sans le nom de l'exécutable.
+    ///
+    /// # Returns
+    ///
+    /// La simulation demandée, `None` sans `--simulate`, ou un message si une option est invalide.
+    pub fn simulation(args: &[String]) -> Result<Option<Simulation>, String> {
+        if !args.iter().any(|arg: &String| arg == "--simulate") {
+            return Ok(None);
+        }
+        let start = option(args, "--simulate").ok_or("Missing start after --simulate")?;
+        let naive = NaiveDateTime::parse_from_str(start, "%Y-%m-%dT%H:%M:%S")
+            .or_else(|_| NaiveDateTime::parse_from_str(start, "%Y-%m-%dT%H:%M"))
+            .map_err(|_| format!("Invalid start {}, expected YYYY-MM-DDTHH:MM", start))?;
+        let start = naive.and_local_timezone(Local)
+            .earliest()
+            .ok_or_else(|| format!("Start {} does not exist in the local time zone", naive))?;
+        let speed = match option(args, "--speed") {
+            Some(speed) => speed.parse::<f64>().map_err(|_| format!("Invalid speed {}", speed))?,
+            None => 1.0,
+        };
+        if !(speed > 0.0 && speed <= MAX_SPEED) {
+            return Err(format!("Speed must be between 0 and {}", MAX_SPEED));
+        }
+        Ok(Some(Simulation {
+            start,
+            speed,
+            null_audio: args.iter().any(|arg: &String| arg == "--null-audio"),
+        }))
+    }
+
+    /// Sous-commande `add`.
+    fn add(args: &[String], json_output: bool) -> Result<(), String> {
+        let new_alarm = parse_new_alarm(args)?;
+        let mut alarm = new_alarm.build(model::next_alarm_id(&load()?))?;
+        if let Some(url) = &new_alarm.url {
+            // L'alarme est tout de même créée, sans chanson.
+            if let Err(error) = download::get_song(&mut alarm, url) {
+                eprintln!("[WARNING] {}", error);
+            }
+        }
+        // Relues après le téléchargement, pour ne pas écraser les changements faits entre-temps.
+        let mut alarms = load()?;
+        if json_output {
+            println!("{}", serde_json::to_string_pretty(&alarm).map_err(|error| error.to_string())?);
+        } else {
+            println!("{}", describe(&alarm));
+        }
+        alarms.push(alarm);
+        save(&alarms)
+    }
+
+    /// Sous-commande `remove`.
+    fn remove(args: &[String]) -> Result<(), String> {
+        let alarm_id = parse_id(args)?;
+        let mut alarms = load()?;
+        let index = alarms.iter()
+            .position(|alarm: &AlarmClock| alarm.a_id == alarm_id)
+            .ok_or_else(|| format!("No alarm with id {}", alarm_id))?;
+        let alarm = alarms.remove(index);
+        if !alarm.is_radio {
+            let _ = std::fs::remove_file(&alarm.song_path);
+        }
+        save(&alarms)
+    }
+
+    /// Sous-commandes `enable` et `disable`.
+    fn set_active(args: &[String], active: bool) -> Result<(), String> {
+        let alarm_id = parse_id(args)?;
+        let mut alarms = load()?;
+        let alarm = alarms.iter_mut()
+            .find(|alarm: &&mut AlarmClock| alarm.a_id == alarm_id)
+            .ok_or_else(|| format!("No alarm with id {}", alarm_id))?;
+        alarm.active = active;
+        save(&alarms)
+    }
+
+    /// Sous-commande `next`.
+    fn next(json_output: bool) -> Result<(), String> {
+        let next = model::next_alarm(&load()?, Local::now())
+            .map(|(alarm, at)| NextAlarm { id: alarm.a_id, name: alarm.name, at });
+        match (next, json_output) {
+            (Some(next), true) => println!("{}", serde_json::to_string_pretty(&next).map_err(|error| error.to_string())?),
+            (Some(next), false) => println!("#{} {} {}", next.id, next.at.format("%a %d/%m %H:%M:%S"), next.name),
+            (None, true) => println!("{}", json!(null)),
+            (None, false) => println!("Aucune alarme programmée"),
+        }
+        Ok(())
+    }
+
+    /// Sous-commande `play-radio`.
+    fn play_radio(args: &[String]) -> Result<(), String> {
+        let name = args.join(" ");
+        let station = RadioStation::from_name(&name).ok_or_else(|| format!("Unknown radio {}", name))?;
+        control::queue(Command::PlayRadio { station: station.label().to_string() }).map_err(|error| format!("Failed to send command {error:?}"))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use chrono::TimeZone;
+
+        fn parse(line: &str) -> Result<Option<Simulation>, String> {
+            let args: Vec<String> = line.split_whitespace().map(str::to_string).collect();
+            simulation(&args)
+        }
+
+        #[test]
+        fn parses_simulation_options() {
+            let warp = parse("--simulate 2026-10-19T06:00 --speed 60 --null-audio").unwrap().unwrap();
+            assert_eq!(warp.start, Local.with_ymd_and_hms(2026, 10, 19, 6, 0, 0).unwrap());
+            assert_eq!(warp.speed, 60.0);
+            assert!(warp.null_audio);
+
+            let warp = parse("--simulate 2026-10-19T06:00:30").unwrap().unwrap();
+            assert_eq!(warp.start, Local.with_ymd_and_hms(2026, 10, 19, 6, 0, 30).unwrap());
+            assert_eq!(warp.speed, 1.0);
+            assert!(!warp.null_audio);
+
+            assert!(parse("--headless").unwrap().is_none());
+            assert!(parse("--simulate tomorrow").is_err());
+            assert!(parse("--simulate 2026-10-19T06:00 --speed 0").is_err());
+            assert!(parse("--simulate 2026-10-19T06:00 --speed 100000").is_err());
+        }
+
+        #[test]
+        fn parses_time_zone_options() {
+            let args = |line: &str| -> Vec<String> { line.split_whitespace().map(str::to_string).collect() };
+
+            let alarm = parse_new_alarm(&args("--time 09:00 --radio RTL --tz America/New_York --dst-nonexistent skip")).unwrap().build(0).unwrap();
+            assert_eq!(alarm.time_zone, Some(chrono_tz::America::New_York));
+            assert_eq!(alarm.dst, DstPolicy { nonexistent: NonexistentTime::Skip, ambiguous: AmbiguousTime::Earliest });
+            assert_eq!(describe(&alarm).split_whitespace().nth(2), Some("America/New_York"));
+
+            let alarm = parse_new_alarm(&args("--time 09:00 --radio RTL")).unwrap().build(0).unwrap();
+            assert_eq!(alarm.time_zone, None);
+
+            assert!(parse_new_alarm(&args("--time 09:00 --radio RTL --tz Mars/Olympus")).unwrap().build(0).is_err());
+            assert!(parse_new_alarm(&args("--time 09:00 --radio RTL --dst-ambiguous both")).is_err());
+        }
+
+        #[test]
+        fn parses_date_option() {
+            let args = |line: &str| -> Vec<String> { line.split_whitespace().map(str::to_string).collect() };
+
+            let alarm = parse_new_alarm(&args("--time 05:45 --radio RTL --date 2026-10-20")).unwrap().build(0).unwrap();
+            assert_eq!(alarm.date, chrono::NaiveDate::from_ymd_opt(2026, 10, 20));
+            assert_eq!(describe(&alarm).split_whitespace().nth(2), Some("20/10/2026"));
+
+            assert!(parse_new_alarm(&args("--time 05:45 --radio RTL --date 20/10/2026")).unwrap().build(0).is_err());
+        }
+    }
+}
@@ -0,0 +1,327 @@
+pub mod config {
+    use serde::{Serialize, Deserialize};
+    use std::fs;
+    use std::io;
+    use crate::errormod::error::{Error, Result};
+    use crate::timermod::timer::{self, Preset};
+    use crate::worldclockmod::worldclock::{self, City};
+
+    const CONFIG_PATH: &str = "ser/config.json";
+
+    /// Configuration du serveur HTTP local.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct HttpConfig {
+        pub enabled: bool,
+        pub bind: String,
+        pub token: Option<String>,
+    }
+
+    impl Default for HttpConfig {
+        fn default() -> Self {
+            HttpConfig {
+                enabled: false,
+                bind: "127.0.0.1:8080".to_string(),
+                token: None,
+            }
+        }
+    }
+
+    /// Configuration de la connexion au broker MQTT.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct MqttConfig {
+        pub enabled: bool,
+        pub host: String,
+        pub port: u16,
+        pub client_id: String,
+        pub username: Option<String>,
+        pub password: Option<String>,
+        pub topic_prefix: String,
+        pub reconnect_secs: u64,
+    }
+
+    impl Default for MqttConfig {
+        fn default() -> Self {
+            MqttConfig {
+                enabled: false,
+                host: "localhost".to_string(),
+                port: 1883,
+                client_id: "alarm_clock".to_string(),
+                username: None,
+                password: None,
+                topic_prefix: "alarm_clock".to_string(),
+                reconnect_secs: 5,
+            }
+        }
+    }
+
+    /// Configuration des notifications de bureau.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct NotificationsConfig {
+        pub enabled: bool,
+        /// Délai du rappel avant une alarme, en minutes ; 0 désactive les rappels.
+        pub reminder_minutes: u32,
+        /// Adresse d'un bus D-Bus privé ; le bus de session si absente.
+        pub bus_address: Option<String>,
+    }
+
+    impl Default for NotificationsConfig {
+        fn default() -> Self {
+            NotificationsConfig {
+                enabled: true,
+                reminder_minutes: 10,
+                bus_address: None,
+            }
+        }
+    }
+
+    /// Configuration de l'interface MPRIS (touches multimédia, widgets du bureau).
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct MprisConfig {
+        pub enabled: bool,
+        /// Adresse d'un bus D-Bus privé ; le bus de session si absente.
+        pub bus_address: Option<String>,
+    }
+
+    impl Default for MprisConfig {
+        fn default() -> Self {
+            MprisConfig {
+                enabled: true,
+                bus_address: None,
+            }
+        }
+    }
+
+    /// Configuration de l'icône de la zone de notification.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct TrayConfig {
+        pub enabled: bool,
+        /// Masque la fenêtre à la fermeture au lieu de quitter ; les alarmes continuent de sonner.
+        pub hide_on_close: bool,
+        /// Stations proposées dans le menu de l'icône.
+        pub favorites: Vec<String>,
+    }
+
+    impl Default for TrayConfig {
+        fn default() -> Self {
+            TrayConfig {
+                enabled: true,
+                hide_on_close: true,
+                favorites: vec!["France Inter".to_string(), "France Info".to_string(), "RTL".to_string()],
+            }
+        }
+    }
+
+    /// Mécanisme utilisé pour sortir la machine de veille avant une alarme.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum WakeBackendKind {
+        /// Écrit l'heure de réveil dans `/sys/class/rtc/rtc0/wakealarm` (droits d'écriture requis).
+        Rtc,
+        /// Programme un minuteur systemd transitoire avec `WakeSystem=yes`.
+        Systemd,
+        /// N'écrit rien : journalise seulement l'heure programmée.
+        Fake,
+    }
+
+    /// Configuration du réveil de la machine en veille avant les alarmes.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct WakeConfig {
+        pub enabled: bool,
+        pub backend: WakeBackendKind,
+        /// Avance du réveil sur l'alarme, en secondes, pour que le son soit prêt.
+        pub lead_secs: u32,
+    }
+
+    impl Default for WakeConfig {
+        fn default() -> Self {
+            // `rtc` demande un accès en écriture à sysfs que les installations ordinaires n'ont pas.
+            WakeConfig {
+                enabled: false,
+                backend: WakeBackendKind::Systemd,
+                lead_secs: 120,
+            }
+        }
+    }
+
+    /// Moteur audio des lecteurs de radio et de fichiers.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    pub enum AudioBackendKind {
+        /// Carte son : GStreamer pour les radios, rodio pour les fichiers.
+        Speakers,
+        /// GStreamer décode les radios et les fichiers, puis jette le son (`fakesink`).
+        Fakesink,
+        /// GStreamer décode les radios et les fichiers dans le fichier WAV `file` (`filesink`).
+        Filesink,
+        /// Aucun décodage : les commandes reçues sont seulement journalisées avec leur heure.
+        Null,
+    }
+
+    /// Configuration de la sortie audio.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct AudioConfig {
+        pub backend: AudioBackendKind,
+        /// Fichier écrit par le moteur `filesink` pour la radio ; les chansons vont dans `<nom>.song.wav`.
+        pub file: String,
+    }
+
+    impl Default for AudioConfig {
+        fn default() -> Self {
+            AudioConfig {
+                backend: AudioBackendKind::Speakers,
+                file: "ser/audio.wav".to_string(),
+            }
+        }
+    }
+
+    /// Configuration du blocage de la mise en veille autour des alarmes.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct InhibitConfig {
+        pub enabled: bool,
+        /// Durée avant une alarme pendant laquelle la mise en veille est bloquée, en minutes.
+        pub window_minutes: u32,
+        /// Adresse d'un bus D-Bus privé ; le bus système si absente.
+        pub bus_address: Option<String>,
+    }
+
+    impl Default for InhibitConfig {
+        fn default() -> Self {
+            InhibitConfig {
+                enabled: true,
+                window_minutes: 5,
+                bus_address: None,
+            }
+        }
+    }
+
+    /// Configuration de la journalisation.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct LoggingConfig {
+        /// Niveaux par module, au format de `RUST_LOG` (`info,rust_first::musicmod=debug`) ; `RUST_LOG` le remplace.
+        pub filter: String,
+        /// Écrit aussi le journal dans `directory`, un fichier par jour.
+        pub file: bool,
+        /// Dossier des fichiers journaux.
+        pub directory: String,
+        /// Nombre de fichiers journaux conservés.
+        pub max_files: usize,
+    }
+
+    impl Default for LoggingConfig {
+        fn default() -> Self {
+            LoggingConfig {
+                filter: "info".to_string(),
+                file: true,
+                directory: "ser/logs".to_string(),
+                max_files: 7,
+            }
+        }
+    }
+
+    /// Configuration de l'horloge mondiale de la fenêtre principale.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct WorldClockConfig {
+        pub enabled: bool,
+        /// Villes affichées, dans l'ordre : `{"name": "New York", "zone": "America/New_York"}`.
+        pub cities: Vec<City>,
+    }
+
+    impl Default for WorldClockConfig {
+        fn default() -> Self {
+            WorldClockConfig {
+                enabled: true,
+                cities: worldclock::default_cities(),
+            }
+        }
+    }
+
+    /// Configuration des minuteurs de la fenêtre principale.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct TimersConfig {
+        /// Minuteurs proposés en un clic : `{"name": "Thé", "seconds": 240}`.
+        pub presets: Vec<Preset>,
+        /// Fichier joué quand un minuteur arrive à zéro ; la radio sélectionnée si absent.
+        pub sound: Option<String>,
+    }
+
+    impl Default for TimersConfig {
+        fn default() -> Self {
+            TimersConfig {
+                presets: timer::default_presets(),
+                sound: None,
+            }
+        }
+    }
+
+    /// Configuration d'un webhook appelé sur les événements d'alarme.
+    #[derive(Clone, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct WebhookConfig {
+        pub url: String,
+        /// Événements transmis (`triggered`, `snoozed`, `dismissed`, `timed_out`, `missed`, `failed`) ; tous si vide.
+        pub events: Vec<String>,
+        pub timeout_secs: u64,
+        pub retries: u32,
+    }
+
+    impl Default for WebhookConfig {
+        fn default() -> Self {
+            WebhookConfig {
+                url: String::new(),
+                events: Vec::new(),
+                timeout_secs: 5,
+                retries: 3,
+            }
+        }
+    }
+
+    /// Configuration de l'application, lue depuis `ser/config.json`.
+    ///
+    /// Toutes les sections sont facultatives : une valeur absente prend sa valeur par défaut.
+    #[derive(Clone, Default, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct Config {
+        pub http: HttpConfig,
+        pub mqtt: MqttConfig,
+        pub webhooks: Vec<WebhookConfig>,
+        pub notifications: NotificationsConfig,
+        pub mpris: MprisConfig,
+        pub tray: TrayConfig,
+        pub wake: WakeConfig,
+        pub inhibit: InhibitConfig,
+        pub logging: LoggingConfig,
+        pub audio: AudioConfig,
+        pub world_clock: WorldClockConfig,
+        pub timers: TimersConfig,
+    }
+
+    impl Config {
+        /// Charge la configuration depuis le fichier de configuration.
+        ///
+        /// # Returns
+        ///
+        /// `Result<Config>` - La configuration, par défaut si le fichier n'existe pas.
+        pub fn load() -> Result<Self> {
+            let contents = match fs::read_to_string(CONFIG_PATH) {
+                Ok(contents) => contents,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+                Err(error) => return Err(Error::Config(format!("{} : {}", CONFIG_PATH, error))),
+            };
+            if contents.trim().is_empty() {
+                return Ok(Config::default());
+            }
+            serde_json::from_str(&contents).map_err(|error| Error::Config(format!("{} : {}", CONFIG_PATH, error)))
+        }
+    }
+}
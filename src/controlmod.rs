@@ -0,0 +1,511 @@
+pub mod control {
+    use chrono::{DateTime, Local, NaiveDate};
+    use serde::{Serialize, Deserialize};
+    use serde_json::{json, Value};
+    use std::fs::{self, OpenOptions};
+    use std::io::{self, Write};
+    use tokio::sync::{broadcast, oneshot};
+    use tracing::error;
+    use crate::downloadmod::download;
+    use crate::errormod::error::{self, Error};
+    use crate::modelmod::model::{self, parse_days, parse_time, AlarmClock, AlarmHooks, DstPolicy, OtherPlayers, RadioStation};
+    use chrono_tz::Tz;
+    use crate::statemod::state::{Persistence, Store};
+
+    const COMMANDS_PATH: &str = "ser/commands.json";
+
+    /// Fichier vers lequel la file est renommée avant d'être lue.
+    const TAKEN_COMMANDS_PATH: &str = "ser/commands.json.taken";
+
+    /// Délai au-delà duquel une commande en attente est ignorée, en secondes.
+    const COMMAND_EXPIRY_SECS: i64 = 10;
+
+    /// Commande transmise à l'instance en cours d'exécution.
+    ///
+    /// Sérialisée sous la forme `{"method": "play_radio", "params": {"station": "France Inter"}}`.
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(tag = "method", content = "params", rename_all = "snake_case")]
+    pub enum Command {
+        ListAlarms,
+        AddAlarm(Box<NewAlarm>),
+        RemoveAlarm { id: usize },
+        ToggleAlarm { id: usize },
+        SetAllActive { active: bool },
+        PlayRadio { station: String },
+        Stop,
+        Snooze,
+        Dismiss,
+    }
+
+    /// Paramètres d'une alarme à créer, au format de la ligne de commande.
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct NewAlarm {
+        pub time: String,
+        pub days: Option<String>,
+        pub radio: Option<String>,
+        pub url: Option<String>,
+        pub name: Option<String>,
+        #[serde(default)]
+        pub hooks: AlarmHooks,
+        #[serde(default)]
+        pub other_players: OtherPlayers,
+        /// Fuseau horaire IANA de l'heure (`America/New_York`) ; celui du système si absent.
+        #[serde(default)]
+        pub time_zone: Option<String>,
+        #[serde(default)]
+        pub dst: DstPolicy,
+        /// Date unique de l'alarme (`2026-10-20`) ; l'alarme sonne chaque semaine si absente.
+        #[serde(default)]
+        pub date: Option<String>,
+    }
+
+    impl NewAlarm {
+        /// Construit l'alarme décrite par les paramètres.
+        ///
+        /// La chanson d'une alarme `url` n'est pas téléchargée ici : voir `download_song`.
+        ///
+        /// # Parameters
+        ///
+        /// * `a_id`: L'identifiant de la nouvelle alarme.
+        ///
+        /// # Returns
+        ///
+        /// L'alarme créée, ou un message d'erreur si un paramètre est invalide.
+        pub fn build(&self, a_id: usize) -> Result<AlarmClock, String> {
+            let (hour, minute, second) = parse_time(&self.time)?;
+            let time_zone = match &self.time_zone {
+                Some(zone) => Some(zone.parse::<Tz>().map_err(|_| format!("Unknown time zone {}", zone))?),
+                None => None,
+            };
+            let date = match &self.date {
+                Some(date) => Some(NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("Invalid date {}", date))?),
+                None => None,
+            };
+            let days = match &self.days {
+                Some(days) => parse_days(days)?,
+                None => [true; 7],
+            };
+            let name = self.name.clone().unwrap_or_default();
+            let mut alarm = match (&self.radio, &self.url) {
+                (Some(radio), None) => {
+                    let station = RadioStation::from_name(radio).ok_or_else(|| format!("Unknown radio {}", radio))?;
+                    AlarmClock::new(a_id, name, hour, minute, second, true, Some(station), days)
+                }
+                // Le lien est passé à yt-dlp : seules les URL web sont acceptées, jamais une option.
+                (None, Some(url)) if url.starts_with("https://") || url.starts_with("http://") => {
+                    AlarmClock::new(a_id, name, hour, minute, second, false, None, days)
+                }
+                (None, Some(url)) => return Err(format!("Expected an http or https URL, got {}", url)),
+                _ => return Err("Expected exactly one of radio or url".to_string()),
+            };
+            alarm.hooks = self.hooks.clone();
+            alarm.other_players = self.other_players.clone();
+            alarm.time_zone = time_zone;
+            alarm.dst = self.dst;
+            alarm.date = date;
+            Ok(alarm)
+        }
+    }
+
+    /// Réponse à une commande : un résultat JSON ou un message d'erreur.
+    pub type Reply = Result<Value, String>;
+
+    /// Commande accompagnée du canal sur lequel renvoyer la réponse.
+    pub struct ControlRequest {
+        pub command: Command,
+        pub reply: oneshot::Sender<Reply>,
+    }
+
+    /// Description d'une alarme jointe aux événements.
+    #[derive(Clone, Debug, Serialize)]
+    pub struct AlarmInfo {
+        pub alarm_id: usize,
+        pub name: String,
+        pub scheduled: String,
+        pub source: String,
+    }
+
+    impl AlarmInfo {
+        /// Crée la description d'une alarme.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarm`: L'alarme à décrire.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance d'`AlarmInfo`.
+        pub fn new(alarm: &AlarmClock) -> Self {
+            AlarmInfo {
+                alarm_id: alarm.a_id,
+                name: alarm.name.clone(),
+                scheduled: alarm.scheduled_label(),
+                source: alarm.source_label(),
+            }
+        }
+    }
+
+    /// Événement diffusé aux clients abonnés.
+    #[derive(Clone, Debug, Serialize)]
+    #[serde(tag = "event", rename_all = "snake_case")]
+    pub enum Event {
+        AlarmFired {
+            #[serde(flatten)]
+            alarm: AlarmInfo,
+        },
+        AlarmSnoozed {
+            #[serde(flatten)]
+            alarm: AlarmInfo,
+            until: DateTime<Local>,
+        },
+        AlarmDismissed {
+            #[serde(flatten)]
+            alarm: AlarmInfo,
+        },
+        /// La sonnerie s'est arrêtée d'elle-même, sans action de l'utilisateur.
+        AlarmTimedOut {
+            #[serde(flatten)]
+            alarm: AlarmInfo,
+        },
+        AlarmMissed {
+            #[serde(flatten)]
+            alarm: AlarmInfo,
+        },
+        PlaybackFailed {
+            #[serde(flatten)]
+            alarm: AlarmInfo,
+        },
+        DownloadFinished {
+            #[serde(flatten)]
+            alarm: AlarmInfo,
+        },
+        DownloadFailed {
+            #[serde(flatten)]
+            alarm: AlarmInfo,
+        },
+        StreamFailed { source: String, error: String },
+        /// Erreur signalée à l'utilisateur (lecture, sauvegarde, configuration).
+        Error { message: String },
+        AlarmsChanged,
+        PlayerState { playing: bool, source: Option<String> },
+        /// Réveil de la machine programmé avant la prochaine alarme, `None` s'il n'y en a pas.
+        WakeArmed { at: Option<DateTime<Local>> },
+        /// Mise en veille bloquée, avec sa raison, ou de nouveau autorisée (`None`).
+        SleepInhibited { reason: Option<String> },
+        /// Un minuteur est arrivé à zéro.
+        TimerFinished { timer_id: usize, name: String },
+    }
+
+    impl Event {
+        /// Construit l'événement de fin de téléchargement de la chanson d'une alarme.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarm`: L'alarme dont la chanson vient d'être téléchargée.
+        ///
+        /// # Returns
+        ///
+        /// `DownloadFinished` ou `DownloadFailed`, ou `None` pour une alarme radio.
+        pub fn download(alarm: &AlarmClock) -> Option<Self> {
+            if alarm.is_radio {
+                return None;
+            }
+            let alarm_info = AlarmInfo::new(alarm);
+            if alarm.is_playable() {
+                Some(Event::DownloadFinished { alarm: alarm_info })
+            } else {
+                Some(Event::DownloadFailed { alarm: alarm_info })
+            }
+        }
+
+        /// Construit l'événement signalant une erreur à l'utilisateur.
+        ///
+        /// # Parameters
+        ///
+        /// * `error`: L'erreur à signaler.
+        ///
+        /// # Returns
+        ///
+        /// Un événement `Error` portant le message de l'erreur.
+        pub fn error(error: &Error) -> Self {
+            Event::Error { message: error.to_string() }
+        }
+
+        /// Construit l'événement d'échec d'un flux radio.
+        ///
+        /// # Parameters
+        ///
+        /// * `url`: L'URL du flux.
+        /// * `error`: Le message d'erreur du lecteur.
+        ///
+        /// # Returns
+        ///
+        /// Un événement `StreamFailed` portant le nom de la station, à défaut l'URL.
+        pub fn stream_failed(url: String, error: String) -> Self {
+            let source = RadioStation::from_url(&url)
+                .map(|station: RadioStation| station.label().to_string())
+                .unwrap_or(url);
+            Event::StreamFailed { source, error }
+        }
+    }
+
+    /// Transmet une commande à l'application et attend sa réponse.
+    ///
+    /// # Parameters
+    ///
+    /// * `requests`: Le canal des commandes de l'application.
+    /// * `command`: La commande à exécuter.
+    ///
+    /// # Returns
+    ///
+    /// La réponse de l'application.
+    pub async fn dispatch(requests: &async_channel::Sender<ControlRequest>, command: Command) -> Reply {
+        let (reply, response) = oneshot::channel();
+        requests.send(ControlRequest { command, reply })
+            .await
+            .map_err(|_| "Application unavailable".to_string())?;
+        response.await.map_err(|_| "No reply from application".to_string())?
+    }
+
+    /// Transmet une commande reçue d'un client distant (socket, HTTP, MQTT) et attend sa réponse.
+    ///
+    /// Les commandes d'une alarme sont exécutées par `sh -c` : elles ne peuvent être fixées que depuis la
+    /// ligne de commande, la fenêtre ou `ser/alarms.json`, et une alarme distante qui en contient est refusée.
+    ///
+    /// # Parameters
+    ///
+    /// * `requests`: Le canal des commandes de l'application.
+    /// * `command`: La commande à exécuter.
+    ///
+    /// # Returns
+    ///
+    /// La réponse de l'application, ou une erreur si la commande définit des commandes d'alarme.
+    pub async fn dispatch_remote(requests: &async_channel::Sender<ControlRequest>, command: Command) -> Reply {
+        if let Command::AddAlarm(new_alarm) = &command {
+            if new_alarm.hooks != AlarmHooks::default() {
+                return Err("Alarm hooks can only be set from the command line or the window".to_string());
+            }
+        }
+        dispatch(requests, command).await
+    }
+
+    /// Télécharge en tâche de fond la chanson d'une alarme déjà ajoutée au `Store`.
+    ///
+    /// `yt-dlp` s'exécute hors de la boucle de l'interface et du démon. Une fois le téléchargement
+    /// terminé, le chemin et le titre de la chanson sont renseignés dans l'alarme, puis
+    /// `Event::DownloadFinished` ou `Event::DownloadFailed` est diffusé.
+    ///
+    /// # Parameters
+    ///
+    /// * `store`: L'état de l'application.
+    /// * `events`: Le diffuseur des événements de l'application.
+    /// * `alarm`: L'alarme, créée sans sa chanson.
+    /// * `url`: Le lien de la chanson.
+    pub fn download_song(store: Store, events: broadcast::Sender<Event>, alarm: AlarmClock, url: String) {
+        tokio::spawn(async move {
+            let a_id = alarm.a_id;
+            let downloaded = tokio::task::spawn_blocking(move || {
+                let mut alarm = alarm;
+                // L'alarme est tout de même gardée : `Event::DownloadFailed` signale l'échec.
+                if let Err(error) = download::get_song(&mut alarm, &url) {
+                    error!(alarm_id = alarm.a_id, %error, "Alarm kept without its song");
+                }
+                alarm
+            }).await;
+            let downloaded = match downloaded {
+                Ok(alarm) => alarm,
+                Err(error) => {
+                    error!(alarm_id = a_id, %error, "Song download task failed");
+                    return;
+                }
+            };
+            let kept = store.update_alarms(|alarms: &mut Vec<AlarmClock>| {
+                match alarms.iter_mut().find(|alarm: &&mut AlarmClock| alarm.a_id == a_id && !alarm.is_radio && alarm.song_path.is_empty()) {
+                    Some(alarm) => {
+                        alarm.song_path = downloaded.song_path.clone();
+                        alarm.song_title = downloaded.song_title.clone();
+                        true
+                    }
+                    None => false,
+                }
+            });
+            if !kept {
+                // L'alarme a été supprimée pendant le téléchargement.
+                if !downloaded.song_path.is_empty() {
+                    let _ = fs::remove_file(&downloaded.song_path);
+                }
+                return;
+            }
+            if let Some(event) = Event::download(&downloaded) {
+                let _ = events.send(event);
+            }
+        });
+    }
+
+    /// Sauvegarde les alarmes à chaque changement du `Store` et relit le fichier modifié par un autre processus.
+    ///
+    /// `Event::AlarmsChanged` est diffusé après chaque sauvegarde ou relecture, `Event::Error` en cas d'échec.
+    ///
+    /// # Parameters
+    ///
+    /// * `store`: L'état de l'application.
+    /// * `events`: Le diffuseur des événements de l'application.
+    pub fn persist(store: Store, events: broadcast::Sender<Event>) {
+        tokio::spawn(Persistence::new(store).run(move |result: error::Result<()>| match result {
+            Ok(()) => {
+                let _ = events.send(Event::AlarmsChanged);
+            }
+            Err(error) => {
+                error!(?error, "Failed to sync alarms");
+                let _ = events.send(Event::error(&error));
+            }
+        }));
+    }
+
+    /// Applique une commande de modification des alarmes sur une liste d'alarmes.
+    ///
+    /// # Parameters
+    ///
+    /// * `alarms`: Les alarmes à modifier.
+    /// * `command`: La commande à appliquer.
+    ///
+    /// # Returns
+    ///
+    /// La réponse, ou `None` si la commande ne concerne pas la liste des alarmes.
+    pub fn edit_alarms(alarms: &mut Vec<AlarmClock>, command: &Command) -> Option<Reply> {
+        let reply = match command {
+            Command::ListAlarms => serde_json::to_value(&*alarms).map_err(|error| error.to_string()),
+            Command::AddAlarm(new_alarm) => new_alarm.build(model::next_alarm_id(alarms)).and_then(|alarm: AlarmClock| {
+                let value = serde_json::to_value(&alarm).map_err(|error| error.to_string());
+                alarms.push(alarm);
+                value
+            }),
+            Command::RemoveAlarm { id } => match alarms.iter().position(|alarm: &AlarmClock| alarm.a_id == *id) {
+                Some(index) => {
+                    let alarm = alarms.remove(index);
+                    if !alarm.is_radio {
+                        let _ = fs::remove_file(&alarm.song_path);
+                    }
+                    Ok(Value::Null)
+                }
+                None => Err(format!("No alarm with id {}", id)),
+            },
+            Command::ToggleAlarm { id } => match alarms.iter_mut().find(|alarm: &&mut AlarmClock| alarm.a_id == *id) {
+                Some(alarm) => {
+                    alarm.active = !alarm.active;
+                    Ok(json!({ "id": id, "active": alarm.active }))
+                }
+                None => Err(format!("No alarm with id {}", id)),
+            },
+            Command::SetAllActive { active } => {
+                for alarm in alarms.iter_mut() {
+                    alarm.active = *active;
+                }
+                Ok(json!({ "active": active }))
+            }
+            Command::PlayRadio { .. } | Command::Stop | Command::Snooze | Command::Dismiss => return None,
+        };
+        Some(reply)
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct PendingCommand {
+        command: Command,
+        sent: DateTime<Local>,
+    }
+
+    /// Ajoute une commande à la file lue par l'instance en cours d'exécution (GUI ou `--headless`).
+    ///
+    /// # Parameters
+    ///
+    /// * `command`: La commande à transmettre.
+    ///
+    /// # Returns
+    ///
+    /// `io::Result<()>` - Résultat de l'écriture dans la file.
+    pub fn queue(command: Command) -> io::Result<()> {
+        let pending = PendingCommand {
+            command,
+            sent: Local::now(),
+        };
+        let mut file = OpenOptions::new().create(true).append(true).open(COMMANDS_PATH)?;
+        writeln!(file, "{}", serde_json::to_string(&pending)?)?;
+        Ok(())
+    }
+
+    /// Retire les commandes en attente de la file.
+    ///
+    /// Les commandes plus anciennes que `COMMAND_EXPIRY_SECS` sont ignorées, afin qu'une
+    /// commande envoyée alors qu'aucune instance ne tournait ne soit pas rejouée au démarrage.
+    /// La file est renommée avant d'être lue : une commande ajoutée pendant la lecture va dans
+    /// une nouvelle file et n'est pas perdue.
+    ///
+    /// # Returns
+    ///
+    /// Les commandes à exécuter, dans l'ordre d'envoi.
+    pub fn take_pending() -> Vec<Command> {
+        if fs::rename(COMMANDS_PATH, TAKEN_COMMANDS_PATH).is_err() {
+            return Vec::new();
+        }
+        let contents = fs::read_to_string(TAKEN_COMMANDS_PATH).unwrap_or_default();
+        let _ = fs::remove_file(TAKEN_COMMANDS_PATH);
+        let now = Local::now();
+        contents.lines()
+            .filter_map(|line: &str| serde_json::from_str::<PendingCommand>(line).ok())
+            .filter(|pending: &PendingCommand| (now - pending.sent).num_seconds() <= COMMAND_EXPIRY_SECS)
+            .map(|pending: PendingCommand| pending.command)
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn new_alarm(hooks: AlarmHooks) -> Command {
+            Command::AddAlarm(Box::new(NewAlarm {
+                time: "07:30".to_string(),
+                days: Some("mon-fri".to_string()),
+                radio: Some("France Inter".to_string()),
+                url: None,
+                name: None,
+                hooks,
+                other_players: OtherPlayers::default(),
+                time_zone: None,
+                dst: DstPolicy::default(),
+                date: None,
+            }))
+        }
+
+        /// Répond `true` à chaque commande reçue, comme le ferait l'application.
+        fn application() -> async_channel::Sender<ControlRequest> {
+            let (requests, received) = async_channel::unbounded::<ControlRequest>();
+            tokio::spawn(async move {
+                while let Ok(request) = received.recv().await {
+                    let _ = request.reply.send(Ok(json!(true)));
+                }
+            });
+            requests
+        }
+
+        #[tokio::test]
+        async fn remote_alarm_with_hooks_is_refused() {
+            let requests = application();
+            let hooks = AlarmHooks { on_fire: "rm -rf ~".to_string(), ..AlarmHooks::default() };
+            assert!(dispatch_remote(&requests, new_alarm(hooks.clone())).await.is_err());
+            assert_eq!(dispatch_remote(&requests, new_alarm(AlarmHooks::default())).await, Ok(json!(true)));
+            assert_eq!(dispatch(&requests, new_alarm(hooks)).await, Ok(json!(true)));
+        }
+
+        #[test]
+        fn song_url_must_be_http() {
+            let Command::AddAlarm(mut new_alarm) = new_alarm(AlarmHooks::default()) else { unreachable!() };
+            new_alarm.radio = None;
+            for url in ["--exec=touch /tmp/alarm", "file:///etc/passwd", "www.youtube.com/watch?v=x"] {
+                new_alarm.url = Some(url.to_string());
+                assert!(new_alarm.build(0).is_err(), "{url}");
+            }
+            new_alarm.url = Some("https://www.youtube.com/watch?v=x".to_string());
+            assert!(new_alarm.build(0).is_ok());
+        }
+    }
+}
@@ -0,0 +1,313 @@
+pub mod daemon {
+    use serde_json::Value;
+    use std::sync::Arc;
+    use tokio::signal::unix::{signal, Signal, SignalKind};
+    use tokio::sync::broadcast;
+    use tokio::time::interval;
+    use tracing::{error, info};
+    use crate::clockmod::clock::Clock;
+    use crate::controlmod::control::{self, AlarmInfo, Command, ControlRequest, Event, Reply};
+    use crate::historymod::history::History;
+    use crate::hookmod::hook::{self, HookKind};
+    use crate::modelmod::model::{self, AlarmClock, RadioStation};
+    use crate::configmod::config::AudioConfig;
+    use crate::musicmod::music::{self, Player};
+    use crate::schedulermod::scheduler::Scheduler;
+    use crate::statemod::state::Store;
+
+    /// Durée maximale de sonnerie sans interface pour arrêter l'alarme, en minutes.
+    const RING_TIMEOUT_MINUTES: i64 = 15;
+
+    /// Réveil sans interface graphique : planifie les alarmes et pilote les lecteurs.
+    struct Daemon {
+        store: Store,
+        scheduler: Scheduler,
+        radio_player: Player,
+        wav_player: Player,
+        events: broadcast::Sender<Event>,
+        simulated: bool,
+    }
+
+    impl Daemon {
+        /// Crée une nouvelle instance de `Daemon` et charge les alarmes.
+        ///
+        /// # Parameters
+        ///
+        /// * `events` - Diffuseur des événements de l'application.
+        /// * `clock` - Horloge de l'application.
+        /// * `audio` - Configuration de la sortie audio.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `Daemon`.
+        fn new(events: broadcast::Sender<Event>, clock: Arc<dyn Clock>, audio: &AudioConfig) -> Self {
+            // En simulation, l'historique reste en mémoire pour ne pas fausser les statistiques.
+            let history = if clock.is_simulated() {
+                History::default()
+            } else {
+                // Un historique illisible n'est pas écrasé : les événements restent en mémoire.
+                History::load().unwrap_or_else(|error| {
+                    error!(?error, "Failed to load history, keeping it in memory");
+                    History::default()
+                })
+            };
+            let stream_events = events.clone();
+            let (radio_player, wav_player) = music::players(audio, clock.clone(), move |url: String, error: String| {
+                let _ = stream_events.send(Event::stream_failed(url, error));
+            });
+            // Les minuteurs sont chargés pour être resauvegardés tels quels ; ils ne sonnent que dans la fenêtre.
+            let (store, errors) = Store::load();
+            for error in errors.iter() {
+                error!(%error, "Failed to load saved state");
+            }
+            // En simulation, les alarmes restent en mémoire et la file de commandes de l'instance réelle n'est pas lue.
+            let simulated = clock.is_simulated();
+            if !simulated {
+                control::persist(store.clone(), events.clone());
+            }
+            let daemon = Daemon {
+                store,
+                scheduler: Scheduler::new(history, clock),
+                radio_player,
+                wav_player,
+                events,
+                simulated,
+            };
+            info!(count = daemon.store.alarms().len(), "Alarms loaded");
+            daemon
+        }
+
+        /// Recharge les alarmes depuis le fichier de sauvegarde.
+        fn reload(&self) {
+            match model::load_alarms() {
+                Ok(alarms) => {
+                    info!(count = alarms.len(), "Alarms loaded");
+                    self.store.reload_alarms(alarms);
+                }
+                Err(error) => error!(?error, "Failed to load alarms"),
+            }
+        }
+
+        /// Transmet au planificateur les alarmes du `Store`, après chacun de ses changements.
+        fn sync_alarms(&mut self) {
+            self.scheduler.set_alarms(self.store.alarms());
+        }
+
+        /// Vérifie les alarmes et arrête celles qui sonnent depuis trop longtemps.
+        fn check_alarms(&mut self) {
+            let now = self.scheduler.now();
+            let expired = self.scheduler.ringing()
+                .map(|ringing| (now - ringing.started).num_minutes() >= RING_TIMEOUT_MINUTES)
+                .unwrap_or(false);
+            if expired {
+                info!(minutes = RING_TIMEOUT_MINUTES, "Alarm rang too long, stopping");
+                // Arrêt automatique : `AlarmTimedOut` à la place de `AlarmDismissed`, sans commande `on_dismiss`.
+                self.stop_player();
+                if let Some(alarm) = self.scheduler.time_out() {
+                    self.emit(Event::AlarmTimedOut { alarm: AlarmInfo::new(&alarm) });
+                }
+            }
+
+            if !self.simulated {
+                for command in control::take_pending() {
+                    if let Err(error) = self.execute(command) {
+                        error!(%error, "Command failed");
+                    }
+                }
+            }
+
+            let tick = self.scheduler.tick();
+            let one_shot_passed = tick.missed.iter().chain(tick.fired.iter()).any(|alarm: &AlarmClock| alarm.date.is_some());
+            for alarm in tick.missed.iter() {
+                self.emit(Event::AlarmMissed { alarm: AlarmInfo::new(alarm) });
+            }
+            for alarm in tick.pre_alarms.iter() {
+                hook::run(alarm, HookKind::PreAlarm);
+            }
+            if let Some(alarm) = tick.fired {
+                self.trigger_alarm(alarm);
+            }
+            // Une alarme à date unique est désactivée une fois passée.
+            if one_shot_passed {
+                self.store.update_alarms(|alarms: &mut Vec<AlarmClock>| model::disable_expired(alarms, now));
+            }
+        }
+
+        /// Exécute une commande reçue d'un autre processus (ligne de commande, socket de contrôle).
+        ///
+        /// # Parameters
+        ///
+        /// * `command` - Commande à exécuter.
+        ///
+        /// # Returns
+        ///
+        /// La réponse à renvoyer au client.
+        fn execute(&mut self, command: Command) -> Reply {
+            info!(?command, "Command received");
+            let mut alarms = self.store.alarms();
+            if let Some(reply) = control::edit_alarms(&mut alarms, &command) {
+                if reply.is_ok() && !matches!(command, Command::ListAlarms) {
+                    let added = alarms.last().cloned();
+                    self.store.update_alarms(|current: &mut Vec<AlarmClock>| *current = alarms);
+                    if let (Command::AddAlarm(new_alarm), Some(alarm)) = (&command, added) {
+                        if let Some(url) = &new_alarm.url {
+                            control::download_song(self.store.clone(), self.events.clone(), alarm, url.clone());
+                        }
+                    }
+                }
+                return reply;
+            }
+
+            match command {
+                Command::PlayRadio { station } => {
+                    let station = RadioStation::from_name(&station).ok_or_else(|| format!("Unknown radio {}", station))?;
+                    self.stop_player();
+                    if let Err(error) = self.radio_player.play(station.url().to_string()) {
+                        error!(%error, "Failed to play radio");
+                        self.emit(Event::error(&error));
+                        return Err(error.to_string());
+                    }
+                    self.emit(Event::PlayerState { playing: true, source: Some(station.label().to_string()) });
+                }
+                Command::Snooze => {
+                    if let Some((alarm, due)) = self.scheduler.snooze() {
+                        self.stop_player();
+                        info!(alarm_id = alarm.a_id, until = %due.format("%H:%M:%S"), "Alarm snoozed");
+                        self.emit(Event::AlarmSnoozed { alarm: AlarmInfo::new(&alarm), until: due });
+                    }
+                }
+                Command::Stop | Command::Dismiss => self.dismiss(),
+                // Déjà exécutées par `control::edit_alarms`.
+                Command::ListAlarms | Command::AddAlarm(_) | Command::RemoveAlarm { .. } | Command::ToggleAlarm { .. } | Command::SetAllActive { .. } => {}
+            }
+            Ok(Value::Null)
+        }
+
+        /// Arrête la lecture et l'alarme en train de sonner.
+        fn dismiss(&mut self) {
+            self.stop_player();
+            if let Some(alarm) = self.scheduler.dismiss() {
+                self.emit(Event::AlarmDismissed { alarm: AlarmInfo::new(&alarm) });
+                hook::run(&alarm, HookKind::Dismiss);
+            }
+        }
+
+        /// Diffuse un événement aux clients abonnés.
+        ///
+        /// # Parameters
+        ///
+        /// * `event` - Événement à diffuser.
+        fn emit(&self, event: Event) {
+            let _ = self.events.send(event);
+        }
+
+        /// Déclenche une alarme et l'enregistre dans l'historique.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarm` - Alarme à déclencher.
+        fn trigger_alarm(&mut self, alarm: AlarmClock) {
+            if !alarm.is_playable() {
+                error!(alarm_id = alarm.a_id, "Nothing to play for alarm");
+                self.scheduler.failed(&alarm);
+                self.emit(Event::PlaybackFailed { alarm: AlarmInfo::new(&alarm) });
+                return;
+            }
+
+            info!(alarm_id = alarm.a_id, name = %alarm.name, source = %alarm.source_label(), "Alarm ringing");
+            self.stop_player();
+            let started = match &alarm.a_radio {
+                Some(station) if alarm.is_radio => self.radio_player.play(station.url().to_string()),
+                _ => self.wav_player.play(alarm.song_path.clone()),
+            };
+            // Une alarme qui ne peut pas être lue est enregistrée comme échouée ; les suivantes sonneront.
+            if let Err(error) = started {
+                error!(alarm_id = alarm.a_id, %error, "Alarm failed");
+                self.scheduler.failed(&alarm);
+                self.emit(Event::error(&error));
+                self.emit(Event::PlaybackFailed { alarm: AlarmInfo::new(&alarm) });
+                return;
+            }
+            self.emit(Event::PlayerState { playing: true, source: Some(alarm.source_label()) });
+            self.emit(Event::AlarmFired { alarm: AlarmInfo::new(&alarm) });
+            hook::run(&alarm, HookKind::Fire);
+            self.scheduler.fired(alarm);
+        }
+
+        /// Arrête le lecteur de musique et de radio.
+        fn stop_player(&mut self) {
+            self.radio_player.stop();
+            self.wav_player.stop();
+            self.emit(Event::PlayerState { playing: false, source: None });
+        }
+    }
+
+    /// Écoute un signal Unix.
+    ///
+    /// # Returns
+    ///
+    /// Le flux des signaux reçus, ou `None` si le signal ne peut pas être écouté : l'erreur est
+    /// journalisée et le réveil continue sans ce signal.
+    fn listen(kind: SignalKind, name: &str) -> Option<Signal> {
+        match signal(kind) {
+            Ok(signal) => Some(signal),
+            Err(error) => {
+                error!(%error, signal = name, "Failed to listen for signal");
+                None
+            }
+        }
+    }
+
+    /// Attend le prochain signal ; ne se termine jamais si le signal n'est pas écouté.
+    async fn received(signal: &mut Option<Signal>) {
+        match signal {
+            Some(signal) => {
+                signal.recv().await;
+            }
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Lance le réveil sans interface graphique.
+    ///
+    /// Les alarmes sont vérifiées chaque seconde et transmises au planificateur à chaque changement
+    /// du `Store` ; `SIGHUP` recharge `ser/alarms.json`,
+    /// `SIGTERM` et `SIGINT` arrêtent la lecture et terminent le processus.
+    ///
+    /// # Parameters
+    ///
+    /// * `requests` - Récepteur des commandes de la socket de contrôle.
+    /// * `events` - Diffuseur des événements de l'application.
+    /// * `clock` - Horloge de l'application.
+    /// * `audio` - Configuration de la sortie audio.
+    pub async fn run(requests: async_channel::Receiver<ControlRequest>, events: broadcast::Sender<Event>, clock: Arc<dyn Clock>, audio: &AudioConfig) {
+        info!("Starting headless alarm clock");
+        let mut ticker = interval(clock.check_interval());
+        let mut daemon = Daemon::new(events, clock, audio);
+        let mut changes = daemon.store.subscribe();
+        daemon.sync_alarms();
+        let mut sigterm = listen(SignalKind::terminate(), "SIGTERM");
+        let mut sighup = listen(SignalKind::hangup(), "SIGHUP");
+        let mut sigint = listen(SignalKind::interrupt(), "SIGINT");
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => daemon.check_alarms(),
+                _ = changes.recv() => daemon.sync_alarms(),
+                Ok(request) = requests.recv() => {
+                    let reply = daemon.execute(request.command);
+                    let _ = request.reply.send(reply);
+                }
+                _ = received(&mut sighup) => {
+                    info!("SIGHUP received, reloading alarms");
+                    daemon.reload();
+                }
+                _ = received(&mut sigterm) => break,
+                _ = received(&mut sigint) => break,
+            }
+        }
+
+        daemon.stop_player();
+        info!("Headless alarm clock stopped");
+    }
+}
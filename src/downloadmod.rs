@@ -0,0 +1,68 @@
+pub mod download {
+    use std::fs;
+    use std::process::Command;
+    use tracing::{error, info, warn};
+    use crate::errormod::error::{Error, Result};
+    use crate::modelmod::model::AlarmClock;
+
+    /// Télécharge la chanson à partir du lien fourni et renseigne son titre et son chemin dans l'alarme.
+    ///
+    /// La chanson est enregistrée dans `song/Alarm_<id>.wav`. Un titre introuvable n'est pas
+    /// une erreur : la chanson reste jouable.
+    ///
+    /// # Parameters
+    ///
+    /// * `alarm`: L'alarme à laquelle associer la chanson.
+    /// * `link`: Le lien de la musique.
+    ///
+    /// # Returns
+    ///
+    /// `Result<()>` - `Error::Download` si `yt-dlp` est absent ou si le téléchargement a échoué.
+    pub fn get_song(alarm: &mut AlarmClock, link: &str) -> Result<()> {
+        let song_path = format!("song/Alarm_{}.wav", alarm.a_id);
+        info!(alarm_id = alarm.a_id, link, "Downloading song");
+        let status = Command::new("yt-dlp")
+            .args([
+                "--format", "bestaudio",
+                "--extract-audio",
+                "--audio-format", "wav",
+                "--cookies-from-browser", "firefox",
+                "--output", song_path.as_str(),
+                // Le lien n'est jamais lu comme une option de yt-dlp.
+                "--",
+                link,
+            ])
+            .status()
+            .map_err(|error| Error::Download(format!("yt-dlp : {}", error)));
+        let downloaded = match status {
+            Ok(status) if !status.success() => Err(Error::Download(format!("yt-dlp : {}", status))),
+            Ok(_) if fs::metadata(&song_path).is_err() => Err(Error::Download(format!("fichier absent : {}", song_path))),
+            other => other.map(|_| ()),
+        };
+        if let Err(error) = downloaded {
+            error!(alarm_id = alarm.a_id, link, %error, "Song download failed");
+            return Err(error);
+        }
+
+        let mut song_title = String::new();
+        match Command::new("yt-dlp")
+            .args(["--get-title", "--cookies-from-browser", "firefox", "--print", "title", "--", link])
+            .output()
+        {
+            Ok(output) if output.status.success() => {
+                song_title = String::from_utf8_lossy(&output.stdout).to_string();
+            }
+            Ok(output) => {
+                let error_message = String::from_utf8_lossy(&output.stderr).to_string();
+                warn!(alarm_id = alarm.a_id, error = error_message.trim_end(), "Failed to retrieve song title");
+            }
+            Err(error) => warn!(alarm_id = alarm.a_id, ?error, "Failed to retrieve song title"),
+        }
+
+        let split_title: Vec<&str> = song_title.split('\n').collect();
+        alarm.song_title = split_title[0].to_string();
+        alarm.song_path = song_path;
+        info!(alarm_id = alarm.a_id, title = %alarm.song_title, path = %alarm.song_path, "Song downloaded");
+        Ok(())
+    }
+}
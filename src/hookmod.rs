@@ -0,0 +1,146 @@
+pub mod hook {
+    use std::process::Stdio;
+    use tokio::process::Command;
+    use tokio::time::{timeout, Duration};
+    use tracing::{error, info, warn};
+    use crate::controlmod::control::AlarmInfo;
+    use crate::modelmod::model::AlarmClock;
+
+    /// Durée maximale d'exécution d'une commande, en secondes ; au-delà elle est arrêtée.
+    const HOOK_TIMEOUT_SECS: u64 = 60;
+
+    /// Moment de l'alarme auquel une commande est lancée.
+    #[derive(Clone, Copy, Debug)]
+    pub enum HookKind {
+        PreAlarm,
+        Fire,
+        Dismiss,
+    }
+
+    impl HookKind {
+        /// Retourne le nom du moment, transmis dans `ALARM_EVENT`.
+        pub fn label(&self) -> &'static str {
+            match self {
+                HookKind::PreAlarm => "pre_alarm",
+                HookKind::Fire => "fire",
+                HookKind::Dismiss => "dismiss",
+            }
+        }
+    }
+
+    /// Lance en tâche de fond la commande d'une alarme pour le moment donné.
+    ///
+    /// La commande est exécutée par `sh -c` avec les variables d'environnement
+    /// `ALARM_ID`, `ALARM_NAME`, `ALARM_TIME`, `ALARM_SOURCE` et `ALARM_EVENT`.
+    /// L'appel rend la main immédiatement ; la sortie est écrite dans le journal.
+    ///
+    /// # Parameters
+    ///
+    /// * `alarm` - Alarme concernée.
+    /// * `kind` - Moment de l'alarme.
+    pub fn run(alarm: &AlarmClock, kind: HookKind) {
+        let command = match kind {
+            HookKind::PreAlarm => &alarm.hooks.pre_alarm,
+            HookKind::Fire => &alarm.hooks.on_fire,
+            HookKind::Dismiss => &alarm.hooks.on_dismiss,
+        };
+        if command.trim().is_empty() {
+            return;
+        }
+        let command = command.clone();
+        let info = AlarmInfo::new(alarm);
+        tokio::spawn(async move {
+            if let Err(error) = execute(&command, &info, kind, Duration::from_secs(HOOK_TIMEOUT_SECS)).await {
+                error!(alarm_id = info.alarm_id, hook = kind.label(), %error, "Hook failed");
+            }
+        });
+    }
+
+    /// Exécute une commande et journalise sa sortie ; elle est tuée si elle dure plus de `limit`.
+    async fn execute(command: &str, info: &AlarmInfo, kind: HookKind, limit: Duration) -> Result<(), String> {
+        info!(alarm_id = info.alarm_id, hook = kind.label(), command, "Running hook");
+        let child = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .env("ALARM_ID", info.alarm_id.to_string())
+            .env("ALARM_NAME", &info.name)
+            .env("ALARM_TIME", &info.scheduled)
+            .env("ALARM_SOURCE", &info.source)
+            .env("ALARM_EVENT", kind.label())
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|error| format!("spawn failed {error:?}"))?;
+
+        let output = timeout(limit, child.wait_with_output())
+            .await
+            .map_err(|_| format!("timed out after {} s, killed", limit.as_secs_f32()))?
+            .map_err(|error| format!("wait failed {error:?}"))?;
+
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            info!(alarm_id = info.alarm_id, hook = kind.label(), "{}", line);
+        }
+        for line in String::from_utf8_lossy(&output.stderr).lines() {
+            warn!(alarm_id = info.alarm_id, hook = kind.label(), "{}", line);
+        }
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(format!("exited with {}", output.status))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::time::Instant;
+        use tokio::time::sleep;
+
+        fn info() -> AlarmInfo {
+            AlarmInfo {
+                alarm_id: 3,
+                name: "Travail".to_string(),
+                scheduled: "07:30:00".to_string(),
+                source: "France Inter".to_string(),
+            }
+        }
+
+        #[tokio::test]
+        async fn hook_receives_the_alarm_variables() {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("env");
+            let command = format!(
+                "printf '%s|%s|%s|%s|%s' \"$ALARM_ID\" \"$ALARM_NAME\" \"$ALARM_TIME\" \"$ALARM_SOURCE\" \"$ALARM_EVENT\" > '{}'",
+                path.display()
+            );
+            execute(&command, &info(), HookKind::Fire, Duration::from_secs(5)).await.unwrap();
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), "3|Travail|07:30:00|France Inter|fire");
+        }
+
+        #[tokio::test]
+        async fn failing_hook_reports_its_status() {
+            let error = execute("exit 4", &info(), HookKind::Dismiss, Duration::from_secs(5)).await.unwrap_err();
+            assert!(error.contains("exited"), "{error}");
+        }
+
+        #[tokio::test]
+        async fn slow_hook_times_out() {
+            let start = Instant::now();
+            let error = execute("sleep 5", &info(), HookKind::PreAlarm, Duration::from_millis(200)).await.unwrap_err();
+            assert!(error.starts_with("timed out"), "{error}");
+            assert!(start.elapsed() < Duration::from_secs(2));
+        }
+
+        #[tokio::test]
+        async fn timed_out_hook_is_killed() {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("killed");
+            let command = format!("sleep 1; touch '{}'", path.display());
+            assert!(execute(&command, &info(), HookKind::Fire, Duration::from_millis(200)).await.is_err());
+            sleep(Duration::from_millis(1500)).await;
+            assert!(!path.exists(), "the hook kept running after its timeout");
+        }
+    }
+}
@@ -0,0 +1,149 @@
+pub mod http {
+    use axum::extract::{Path, Request, State};
+    use axum::http::{header, HeaderMap, StatusCode};
+    use axum::middleware::{self, Next};
+    use axum::response::{Html, IntoResponse, Response};
+    use axum::routing::{delete, get, post};
+    use axum::{Json, Router};
+    use serde::Deserialize;
+    use serde_json::{json, Value};
+    use tokio::net::TcpListener;
+    use tracing::{error, info};
+    use crate::configmod::config::HttpConfig;
+    use crate::controlmod::control::{self, Command, ControlRequest, NewAlarm};
+    use crate::modelmod::model::RadioStation;
+
+    /// Page web embarquée dans l'exécutable.
+    const INDEX_HTML: &str = include_str!("../web/index.html");
+
+    /// État partagé par les gestionnaires de requêtes.
+    #[derive(Clone)]
+    struct HttpState {
+        requests: async_channel::Sender<ControlRequest>,
+        token: String,
+    }
+
+    /// Corps de la requête `POST /api/player/play`.
+    #[derive(Deserialize)]
+    struct PlayRequest {
+        station: String,
+    }
+
+    /// Démarre le serveur HTTP en tâche de fond si la configuration l'active.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - Configuration du serveur HTTP.
+    /// * `requests` - Canal des commandes exécutées par l'application.
+    pub fn spawn(config: HttpConfig, requests: async_channel::Sender<ControlRequest>) {
+        if !config.enabled {
+            return;
+        }
+        let Some(token) = config.token.clone().filter(|token: &String| !token.is_empty()) else {
+            error!("HTTP server enabled without http.token in ser/config.json, not starting");
+            return;
+        };
+        let state = HttpState { requests, token };
+        tokio::spawn(async move {
+            if let Err(error) = serve(&config.bind, state).await {
+                error!(?error, "HTTP server stopped");
+            }
+        });
+    }
+
+    /// Construit les routes et sert les requêtes.
+    async fn serve(bind: &str, state: HttpState) -> std::io::Result<()> {
+        let api = Router::new()
+            .route("/api/alarms", get(list_alarms).post(add_alarm))
+            .route("/api/alarms/:id", delete(remove_alarm))
+            .route("/api/alarms/:id/toggle", post(toggle_alarm))
+            .route("/api/stations", get(list_stations))
+            .route("/api/player/play", post(play))
+            .route("/api/player/stop", post(stop))
+            .route("/api/player/snooze", post(snooze))
+            .route("/api/player/dismiss", post(dismiss))
+            .route_layer(middleware::from_fn_with_state(state.clone(), require_token));
+        let app = Router::new()
+            .route("/", get(index))
+            .merge(api)
+            .with_state(state);
+
+        let listener = TcpListener::bind(bind).await?;
+        info!("HTTP server listening on http://{}", bind);
+        axum::serve(listener, app).await
+    }
+
+    /// Refuse les requêtes de l'API sans le jeton `Authorization: Bearer <token>`.
+    async fn require_token(State(state): State<HttpState>, headers: HeaderMap, request: Request, next: Next) -> Response {
+        let expected = format!("Bearer {}", state.token);
+        let authorized = headers.get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .map(|value: &str| value == expected)
+            .unwrap_or(false);
+        if !authorized {
+            return (StatusCode::UNAUTHORIZED, Json(json!({ "error": "Invalid or missing token" }))).into_response();
+        }
+        next.run(request).await
+    }
+
+    /// Transmet une commande à l'application et convertit sa réponse en réponse HTTP.
+    async fn run(state: &HttpState, command: Command) -> Response {
+        match control::dispatch_remote(&state.requests, command).await {
+            Ok(result) => Json(result).into_response(),
+            Err(error) => (StatusCode::BAD_REQUEST, Json(json!({ "error": error }))).into_response(),
+        }
+    }
+
+    /// `GET /` : page web de réglage des alarmes.
+    async fn index() -> Html<&'static str> {
+        Html(INDEX_HTML)
+    }
+
+    /// `GET /api/alarms`
+    async fn list_alarms(State(state): State<HttpState>) -> Response {
+        run(&state, Command::ListAlarms).await
+    }
+
+    /// `POST /api/alarms`
+    async fn add_alarm(State(state): State<HttpState>, Json(new_alarm): Json<NewAlarm>) -> Response {
+        run(&state, Command::AddAlarm(Box::new(new_alarm))).await
+    }
+
+    /// `DELETE /api/alarms/:id`
+    async fn remove_alarm(State(state): State<HttpState>, Path(id): Path<usize>) -> Response {
+        run(&state, Command::RemoveAlarm { id }).await
+    }
+
+    /// `POST /api/alarms/:id/toggle`
+    async fn toggle_alarm(State(state): State<HttpState>, Path(id): Path<usize>) -> Response {
+        run(&state, Command::ToggleAlarm { id }).await
+    }
+
+    /// `GET /api/stations`
+    async fn list_stations() -> Json<Value> {
+        let stations: Vec<Value> = RadioStation::ALL.iter()
+            .map(|station: &RadioStation| json!({ "name": station.label(), "url": station.url() }))
+            .collect();
+        Json(Value::Array(stations))
+    }
+
+    /// `POST /api/player/play`
+    async fn play(State(state): State<HttpState>, Json(body): Json<PlayRequest>) -> Response {
+        run(&state, Command::PlayRadio { station: body.station }).await
+    }
+
+    /// `POST /api/player/stop`
+    async fn stop(State(state): State<HttpState>) -> Response {
+        run(&state, Command::Stop).await
+    }
+
+    /// `POST /api/player/snooze`
+    async fn snooze(State(state): State<HttpState>) -> Response {
+        run(&state, Command::Snooze).await
+    }
+
+    /// `POST /api/player/dismiss`
+    async fn dismiss(State(state): State<HttpState>) -> Response {
+        run(&state, Command::Dismiss).await
+    }
+}
@@ -0,0 +1,339 @@
+pub mod inhibit {
+    use chrono::{DateTime, Duration as ChronoDuration, Local};
+    use tokio::sync::broadcast::{self, error::RecvError};
+    use tokio::time::{interval, Duration};
+    use tracing::{error, info};
+    use zbus::zvariant::OwnedFd;
+    use zbus::{Connection, ConnectionBuilder};
+    use crate::configmod::config::InhibitConfig;
+    use crate::controlmod::control::{self, Command, ControlRequest, Event};
+    use crate::logindmod::logind::LoginManagerProxy;
+    use crate::modelmod::model::{self, AlarmClock};
+
+    /// Intervalle de vérification de l'approche de la prochaine alarme, en secondes.
+    const CHECK_SECS: u64 = 30;
+
+    const REASON_RINGING: &str = "Alarme en cours";
+    const REASON_IMMINENT: &str = "Alarme imminente";
+
+    /// Décide si la mise en veille doit être bloquée, d'après les alarmes et les événements reçus.
+    pub struct InhibitPolicy {
+        window: ChronoDuration,
+        ringing: bool,
+        snoozed_until: Option<DateTime<Local>>,
+    }
+
+    impl InhibitPolicy {
+        /// Crée une nouvelle instance d'`InhibitPolicy`.
+        ///
+        /// # Parameters
+        ///
+        /// * `window_minutes`: Durée avant une alarme pendant laquelle la mise en veille est bloquée.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance d'`InhibitPolicy`, sans alarme en cours.
+        pub fn new(window_minutes: u32) -> Self {
+            InhibitPolicy {
+                window: ChronoDuration::minutes(window_minutes as i64),
+                ringing: false,
+                snoozed_until: None,
+            }
+        }
+
+        /// Suit les sonneries, reports et arrêts d'alarme.
+        ///
+        /// # Parameters
+        ///
+        /// * `event`: L'événement de l'application.
+        pub fn update(&mut self, event: &Event) {
+            match event {
+                Event::AlarmFired { .. } => {
+                    self.ringing = true;
+                    self.snoozed_until = None;
+                }
+                Event::AlarmSnoozed { until, .. } => {
+                    self.ringing = false;
+                    self.snoozed_until = Some(*until);
+                }
+                Event::AlarmDismissed { .. } | Event::AlarmTimedOut { .. } => {
+                    self.ringing = false;
+                    self.snoozed_until = None;
+                }
+                _ => {}
+            }
+        }
+
+        /// Retourne la raison de bloquer la mise en veille.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarms`: Les alarmes.
+        /// * `now`: L'instant présent.
+        ///
+        /// # Returns
+        ///
+        /// La raison si une alarme sonne, ou si une alarme ou un report tombe dans la fenêtre ; `None` sinon.
+        pub fn reason(&self, alarms: &[AlarmClock], now: DateTime<Local>) -> Option<&'static str> {
+            if self.ringing {
+                return Some(REASON_RINGING);
+            }
+            let until = now + self.window;
+            let snooze_due = self.snoozed_until.is_some_and(|due: DateTime<Local>| due > now && due <= until);
+            let alarm_due = model::next_alarm(alarms, now).is_some_and(|(_, at)| at <= until);
+            if snooze_due || alarm_due {
+                Some(REASON_IMMINENT)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Verrou d'inhibition de systemd-logind, pris et relâché à la demande.
+    pub struct Inhibitor {
+        logind: LoginManagerProxy<'static>,
+        lock: Option<OwnedFd>,
+    }
+
+    impl Inhibitor {
+        /// Crée une nouvelle instance d'`Inhibitor`, sans verrou.
+        ///
+        /// # Parameters
+        ///
+        /// * `connection`: Connexion au bus de systemd-logind.
+        pub async fn new(connection: &Connection) -> zbus::Result<Self> {
+            Ok(Inhibitor {
+                logind: LoginManagerProxy::new(connection).await?,
+                lock: None,
+            })
+        }
+
+        /// Indique si le verrou est pris.
+        #[cfg(test)]
+        pub fn is_held(&self) -> bool {
+            self.lock.is_some()
+        }
+
+        /// Prend le verrou avec la raison donnée, ou le relâche avec `None`.
+        ///
+        /// Un verrou déjà pris est conservé quand seule la raison change.
+        ///
+        /// # Parameters
+        ///
+        /// * `reason`: La raison affichée par `systemd-inhibit --list`.
+        pub async fn set(&mut self, reason: Option<&str>) -> zbus::Result<()> {
+            match reason {
+                Some(why) if self.lock.is_none() => {
+                    self.lock = Some(self.logind.inhibit("sleep:idle", "Alarm Clock", why, "block").await?);
+                }
+                Some(_) => {}
+                // Fermer le descripteur relâche le verrou.
+                None => self.lock = None,
+            }
+            Ok(())
+        }
+    }
+
+    /// Bloque en tâche de fond la mise en veille autour des alarmes, si la configuration l'active.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - Configuration du blocage.
+    /// * `requests` - Canal des commandes exécutées par l'application, pour lire les alarmes.
+    /// * `events` - Diffuseur des événements de l'application.
+    pub fn spawn(config: InhibitConfig, requests: async_channel::Sender<ControlRequest>, events: &broadcast::Sender<Event>) {
+        if !config.enabled {
+            return;
+        }
+        let events = events.clone();
+        tokio::spawn(async move {
+            if let Err(error) = run(config, requests, events).await {
+                error!(?error, "Sleep inhibitor unavailable");
+            }
+        });
+    }
+
+    /// Suit les alarmes et prend ou relâche le verrou en conséquence.
+    async fn run(config: InhibitConfig, requests: async_channel::Sender<ControlRequest>, events: broadcast::Sender<Event>) -> zbus::Result<()> {
+        let connection = match config.bus_address.as_deref() {
+            Some(address) => ConnectionBuilder::address(address)?.build().await?,
+            None => Connection::system().await?,
+        };
+        let mut inhibitor = Inhibitor::new(&connection).await?;
+        let mut policy = InhibitPolicy::new(config.window_minutes);
+        let mut receiver = events.subscribe();
+        let mut checks = interval(Duration::from_secs(CHECK_SECS));
+        let mut alarms: Vec<AlarmClock> = Vec::new();
+        let mut current: Option<&'static str> = None;
+
+        loop {
+            tokio::select! {
+                event = receiver.recv() => match event {
+                    Ok(event) => {
+                        if let Event::AlarmsChanged = event {
+                            alarms = list_alarms(&requests).await.unwrap_or(alarms);
+                        }
+                        policy.update(&event);
+                    }
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                },
+                _ = checks.tick() => {
+                    alarms = list_alarms(&requests).await.unwrap_or(alarms);
+                }
+            }
+            let reason = policy.reason(&alarms, Local::now());
+            if reason == current {
+                continue;
+            }
+            if let Err(error) = inhibitor.set(reason).await {
+                error!(?error, "Failed to change sleep inhibitor");
+                continue;
+            }
+            match reason {
+                Some(reason) => info!(reason, "Sleep inhibited"),
+                None => info!("Sleep allowed again"),
+            }
+            current = reason;
+            let _ = events.send(Event::SleepInhibited { reason: reason.map(str::to_string) });
+        }
+        Ok(())
+    }
+
+    /// Lit les alarmes de l'application.
+    async fn list_alarms(requests: &async_channel::Sender<ControlRequest>) -> Option<Vec<AlarmClock>> {
+        match control::dispatch(requests, Command::ListAlarms).await {
+            Ok(alarms) => serde_json::from_value(alarms).ok(),
+            Err(error) => {
+                error!(%error, "Sleep inhibitor failed to list alarms");
+                None
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::io::{ErrorKind, Read};
+        use std::os::unix::io::AsRawFd;
+        use std::os::unix::net::UnixStream;
+        use std::sync::{Arc, Mutex};
+        use zbus::dbus_interface;
+        use zbus::zvariant::Fd;
+        use crate::controlmod::control::AlarmInfo;
+        use crate::testmod::fixtures::{alarm, at, PrivateBus};
+
+        /// Verrou remis par le faux logind : `peer` voit la fin de fichier quand le client le relâche.
+        struct FakeLock {
+            what: String,
+            mode: String,
+            peer: UnixStream,
+            given: Option<UnixStream>,
+        }
+
+        /// systemd-logind simulé, qui enregistre les verrous demandés.
+        struct FakeLogind {
+            locks: Arc<Mutex<Vec<FakeLock>>>,
+        }
+
+        #[dbus_interface(name = "org.freedesktop.login1.Manager")]
+        impl FakeLogind {
+            fn inhibit(&self, what: &str, _who: &str, _why: &str, mode: &str) -> Fd {
+                let (given, peer) = UnixStream::pair().unwrap();
+                peer.set_nonblocking(true).unwrap();
+                let fd = Fd::from(given.as_raw_fd());
+                self.locks.lock().unwrap().push(FakeLock {
+                    what: what.to_string(),
+                    mode: mode.to_string(),
+                    peer,
+                    given: Some(given),
+                });
+                fd
+            }
+        }
+
+        /// Indique si le client a relâché le verrou, en attendant un peu la fermeture.
+        async fn released(lock: &mut FakeLock) -> bool {
+            for _ in 0..50 {
+                match lock.peer.read(&mut [0u8; 1]) {
+                    Ok(0) => return true,
+                    Err(error) if error.kind() == ErrorKind::WouldBlock => {}
+                    _ => return false,
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            false
+        }
+
+        #[tokio::test]
+        async fn takes_and_releases_lock_on_mock_logind() {
+            let bus = PrivateBus::start();
+            let address = bus.address.clone();
+            let locks = Arc::new(Mutex::new(Vec::new()));
+            let _logind = ConnectionBuilder::address(address.as_str()).unwrap()
+                .name("org.freedesktop.login1").unwrap()
+                .serve_at("/org/freedesktop/login1", FakeLogind { locks: locks.clone() }).unwrap()
+                .build()
+                .await
+                .unwrap();
+            let client = ConnectionBuilder::address(address.as_str()).unwrap().build().await.unwrap();
+            let mut inhibitor = Inhibitor::new(&client).await.unwrap();
+
+            inhibitor.set(Some(REASON_IMMINENT)).await.unwrap();
+            inhibitor.set(Some(REASON_RINGING)).await.unwrap();
+            assert!(inhibitor.is_held());
+            let mut lock = {
+                let mut locks = locks.lock().unwrap();
+                assert_eq!(locks.len(), 1);
+                // Seul le client garde désormais une copie du descripteur.
+                locks[0].given = None;
+                locks.remove(0)
+            };
+            assert_eq!(lock.what, "sleep:idle");
+            assert_eq!(lock.mode, "block");
+            assert!(!released(&mut lock).await);
+
+            inhibitor.set(None).await.unwrap();
+            assert!(!inhibitor.is_held());
+            assert!(released(&mut lock).await);
+        }
+
+        fn info() -> AlarmInfo {
+            AlarmInfo {
+                alarm_id: 0,
+                name: "Travail".to_string(),
+                scheduled: "07:00:00".to_string(),
+                source: "France Inter".to_string(),
+            }
+        }
+
+        #[test]
+        fn blocks_within_window_before_alarm() {
+            let policy = InhibitPolicy::new(5);
+            let alarms = vec![alarm(7, 0)];
+
+            assert_eq!(policy.reason(&alarms, at(6, 50)), None);
+            assert_eq!(policy.reason(&alarms, at(6, 58)), Some(REASON_IMMINENT));
+            assert_eq!(policy.reason(&alarms, at(7, 1)), None);
+        }
+
+        #[test]
+        fn blocks_while_ringing_and_before_snooze() {
+            let mut policy = InhibitPolicy::new(5);
+
+            policy.update(&Event::AlarmFired { alarm: info() });
+            assert_eq!(policy.reason(&[], at(7, 0)), Some(REASON_RINGING));
+
+            policy.update(&Event::AlarmSnoozed { alarm: info(), until: at(7, 9) });
+            assert_eq!(policy.reason(&[], at(7, 1)), None);
+            assert_eq!(policy.reason(&[], at(7, 5)), Some(REASON_IMMINENT));
+
+            policy.update(&Event::AlarmDismissed { alarm: info() });
+            assert_eq!(policy.reason(&[], at(7, 5)), None);
+
+            policy.update(&Event::AlarmFired { alarm: info() });
+            policy.update(&Event::AlarmTimedOut { alarm: info() });
+            assert_eq!(policy.reason(&[], at(7, 20)), None);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+pub mod logging {
+    use std::fs;
+    use tracing_appender::non_blocking::WorkerGuard;
+    use tracing_appender::rolling::{RollingFileAppender, Rotation};
+    use tracing_subscriber::prelude::*;
+    use tracing_subscriber::{fmt, EnvFilter};
+    use crate::configmod::config::LoggingConfig;
+
+    /// Préfixe des fichiers journaux : `alarm_clock.<date>.log`.
+    const LOG_PREFIX: &str = "alarm_clock";
+
+    /// Niveau utilisé quand le filtre configuré est invalide.
+    const DEFAULT_FILTER: &str = "info";
+
+    /// Installe la journalisation : sortie standard et, si configuré, un fichier par jour.
+    ///
+    /// Les niveaux sont lus dans `RUST_LOG`, à défaut dans `logging.filter` de la configuration.
+    ///
+    /// # Parameters
+    ///
+    /// * `config`: La configuration de la journalisation.
+    ///
+    /// # Returns
+    ///
+    /// Le garde du fichier journal, à conserver jusqu'à la fin du programme pour que tout y soit écrit.
+    pub fn init(config: &LoggingConfig) -> Option<WorkerGuard> {
+        let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+            EnvFilter::try_new(&config.filter).unwrap_or_else(|error| {
+                eprintln!("[ERROR] Invalid logging filter {:?} {error:?}", config.filter);
+                EnvFilter::new(DEFAULT_FILTER)
+            })
+        });
+
+        let (file_layer, guard) = if config.file {
+            let _ = fs::create_dir_all(&config.directory);
+            let appender = RollingFileAppender::builder()
+                .rotation(Rotation::DAILY)
+                .filename_prefix(LOG_PREFIX)
+                .filename_suffix("log")
+                .max_log_files(config.max_files.max(1))
+                .build(&config.directory);
+            match appender {
+                Ok(appender) => {
+                    let (writer, guard) = tracing_appender::non_blocking(appender);
+                    (Some(fmt::layer().with_ansi(false).with_writer(writer)), Some(guard))
+                }
+                Err(error) => {
+                    eprintln!("[ERROR] Log file unavailable in {} {error:?}", config.directory);
+                    (None, None)
+                }
+            }
+        } else {
+            (None, None)
+        };
+
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(fmt::layer())
+            .with(file_layer)
+            .init();
+        guard
+    }
+}
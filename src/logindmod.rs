@@ -0,0 +1,21 @@
+pub mod logind {
+    use zbus::dbus_proxy;
+    use zbus::zvariant::OwnedFd;
+
+    /// Proxy du gestionnaire de sessions systemd-logind, sur le bus système.
+    #[dbus_proxy(
+        interface = "org.freedesktop.login1.Manager",
+        default_service = "org.freedesktop.login1",
+        default_path = "/org/freedesktop/login1"
+    )]
+    trait LoginManager {
+        /// Prend un verrou d'inhibition, relâché à la fermeture du descripteur retourné.
+        ///
+        /// `what` vaut par exemple `sleep` ou `idle`, `mode` vaut `block` ou `delay`.
+        fn inhibit(&self, what: &str, who: &str, why: &str, mode: &str) -> zbus::Result<OwnedFd>;
+
+        /// Émis avec `true` juste avant la mise en veille, et avec `false` au retour.
+        #[dbus_proxy(signal)]
+        fn prepare_for_sleep(&self, start: bool) -> zbus::Result<()>;
+    }
+}
@@ -1,38 +1,197 @@
 use gtk::prelude::*;
+use gtk::gio::{ApplicationCommandLine, ApplicationFlags, Cancellable};
+use gtk::glib::{ExitCode, MainContext, Propagation};
 use gtk::{Application, ApplicationWindow};
+use std::sync::Arc;
+use tracing::{error, info};
+// Cœur sans GTK (modèle, historique, planificateur, horloge, lecteur d'enregistrement), partagé avec les tests.
+use alarm_core::{clockmod, errormod, historymod, modelmod, playermod, schedulermod, statemod, timermod, worldclockmod};
 mod viewmod;
 use viewmod::view::View;
-mod modelmod;
 mod widgetmod;
 mod musicmod;
+mod downloadmod;
+mod hookmod;
+mod inhibitmod;
+mod daemonmod;
+mod controlmod;
+mod climod;
+mod socketmod;
+mod configmod;
+mod loggingmod;
+mod httpmod;
+mod mprismod;
+mod mqttmod;
+mod notifymod;
+mod logindmod;
+mod traymod;
+mod wakemod;
+mod webhookmod;
+#[cfg(test)]
+mod testmod;
+
+/// Démarre la socket de contrôle, l'API HTTP, MQTT, les webhooks, les notifications, MPRIS, la sortie de veille et le blocage de la mise en veille.
+///
+/// Les commandes reçues sont exécutées par la vue ou par le mode sans interface.
+///
+/// # Parameters
+///
+/// * `config` - Configuration de l'application.
+/// * `requests` - Canal des commandes exécutées par l'application.
+/// * `events` - Diffuseur des événements de l'application.
+fn spawn_services(config: &configmod::config::Config, requests: async_channel::Sender<controlmod::control::ControlRequest>, events: &tokio::sync::broadcast::Sender<controlmod::control::Event>) {
+    socketmod::socket::spawn(requests.clone(), events.clone());
+    mqttmod::mqtt::spawn(config.mqtt.clone(), requests.clone(), events);
+    webhookmod::webhook::spawn(config.webhooks.clone(), events);
+    notifymod::notify::spawn(config.notifications.clone(), requests.clone(), events);
+    mprismod::mpris::spawn(config.mpris.clone(), requests.clone(), events);
+    wakemod::wake::spawn(config.wake.clone(), requests.clone(), events);
+    inhibitmod::inhibit::spawn(config.inhibit.clone(), requests.clone(), events);
+    httpmod::http::spawn(config.http.clone(), requests);
+}
 
 #[tokio::main]
 async fn main() {
+    // Sous-commandes en ligne de commande : elles modifient `ser/alarms.json` sans interface.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if climod::cli::is_command(&args) {
+        std::process::exit(climod::cli::run(&args));
+    }
+    let simulation = climod::cli::simulation(&args).unwrap_or_else(|message: String| {
+        eprintln!("[ERROR] {}", message);
+        std::process::exit(1);
+    });
+
+    // Charge la configuration de l'application ; une configuration invalide est signalée dans la fenêtre.
+    let (mut config, config_error) = match configmod::config::Config::load() {
+        Ok(config) => (config, None),
+        Err(error) => (configmod::config::Config::default(), Some(error)),
+    };
+
+    // Journalisation sur la sortie standard et dans `ser/logs/` ; le garde vide le fichier à la sortie.
+    let _log_guard = loggingmod::logging::init(&config.logging);
+    let config_error = config_error.map(|error| {
+        error!(?error, "Failed to load config");
+        error.to_string()
+    });
+
+    // Simulation : l'horloge virtuelle remplace l'horloge système pour l'affichage et les alarmes.
+    let clock: Arc<dyn clockmod::clock::Clock> = match &simulation {
+        Some(simulation) => {
+            info!(start = %simulation.start, speed = simulation.speed, null_audio = simulation.null_audio, "Simulating time");
+            config.tray.enabled = false;
+            if simulation.null_audio {
+                config.audio.backend = configmod::config::AudioBackendKind::Null;
+            }
+            Arc::new(clockmod::clock::SimulatedClock::new(simulation.start, simulation.speed))
+        }
+        None => Arc::new(clockmod::clock::SystemClock),
+    };
+    let (request_sender, request_receiver) = async_channel::unbounded();
+    let (events, _) = tokio::sync::broadcast::channel(64);
+
+    // Mode sans interface graphique : aucune fenêtre GTK n'est créée.
+    if std::env::args().any(|arg: String| arg == "--headless") {
+        if simulation.is_none() {
+            spawn_services(&config, request_sender, &events);
+        }
+        daemonmod::daemon::run(request_receiver, events, clock, &config.audio).await;
+        return;
+    }
+
     // Crée une nouvelle application GTK avec l'identifiant "com.my.alarm_clock".
-    let application: Application = Application::new(
-        Some("com.my.alarm_clock"),
-        Default::default(),
-    );
+    // Une seule instance s'exécute : les lancements suivants lui transmettent leurs arguments.
+    // Une simulation s'exécute à part, à côté de l'instance réelle.
+    let flags = match simulation {
+        Some(_) => ApplicationFlags::HANDLES_COMMAND_LINE | ApplicationFlags::NON_UNIQUE,
+        None => ApplicationFlags::HANDLES_COMMAND_LINE,
+    };
+    let application: Application = Application::new(Some("com.my.alarm_clock"), flags);
+    if let Err(error) = application.register(Cancellable::NONE) {
+        error!(?error, "Failed to register application");
+    }
+    if application.is_remote() {
+        // Instance déjà lancée : elle exécute les arguments et présente sa fenêtre.
+        info!("Alarm clock already running, forwarding arguments");
+        std::process::exit(application.run().value());
+    }
+    // Les alarmes simulées n'atteignent ni les clients (socket, HTTP, MQTT, webhooks) ni la mise en veille réelle.
+    if simulation.is_none() {
+        spawn_services(&config, request_sender.clone(), &events);
+    }
+    let tray_actions = traymod::tray::spawn(&config.tray, request_sender.clone(), &events);
+    // Avec l'icône, la fenêtre fermée est seulement masquée : les alarmes continuent de sonner.
+    let hide_on_close = tray_actions.is_some() && config.tray.hide_on_close;
+
+    // Exécute les actions du menu de l'icône dans la boucle GTK.
+    if let Some(tray_actions) = tray_actions {
+        let app = application.clone();
+        let requests = request_sender.clone();
+        MainContext::default().spawn_local(async move {
+            while let Ok(action) = tray_actions.recv().await {
+                match action {
+                    traymod::tray::TrayAction::Command(command) => {
+                        if let Err(error) = controlmod::control::dispatch(&requests, command).await {
+                            error!(%error, "Tray command failed");
+                        }
+                    }
+                    traymod::tray::TrayAction::ShowWindow => app.activate(),
+                    traymod::tray::TrayAction::Quit => app.quit(),
+                }
+            }
+        });
+    }
 
     // Connecte la fonction de rappel pour l'activation de l'application.
-    application.connect_activate(|app: &Application| {
+    application.connect_activate(move |app: &Application| {
+        // Présente la fenêtre existante plutôt que d'en créer une seconde.
+        if let Some(window) = app.windows().first() {
+            window.present();
+            return;
+        }
+
         // Crée une nouvelle fenêtre de l'application.
         let window: ApplicationWindow = ApplicationWindow::new(app);
+        if hide_on_close {
+            window.connect_delete_event(|window: &ApplicationWindow, _| {
+                window.hide();
+                Propagation::Stop
+            });
+        }
         
+        // Charge les alarmes sauvegardées dans l'état de l'application, s'il y en a.
+        let (store, store_errors) = statemod::state::Store::load();
+        // Les échéances des minuteurs sont en temps réel : une simulation part sans minuteur, et les
+        // minuteurs lancés en temps virtuel ne sont pas sauvegardés (pas de `persist` en simulation).
+        if clock.is_simulated() {
+            store.update_timers(|timers: &mut Vec<timermod::timer::Timer>| timers.clear());
+        }
+
+        // Sauvegarde les alarmes à chaque modification ; en simulation, elles restent en mémoire.
+        if !clock.is_simulated() {
+            controlmod::control::persist(store.clone(), events.clone());
+        }
+
         // Crée une nouvelle instance de la vue.
-        let mut view: View = View::new();
-        
-        // Charge les alarmes sauvegardées, s'il y en a.
-        let _res: () = match view.load_alarms() {
-            Ok(_res) => println!("[INFO]  File loaded"),
-            Err(error) => println!("[ERROR] Failed to load alarms {error:?}"),
-        };
+        let view: View = View::new(events.clone(), clock.clone(), store, &config.audio, &config.world_clock, &config.timers);
 
         // Construit l'interface utilisateur de la vue.
         view.build_ui(&window);
         
         // Connecte les signaux (événements utilisateur) à la vue.
         view.connect_signals();
+
+        // Exécute les commandes reçues sur la socket de contrôle.
+        view.connect_control(request_receiver.clone());
+
+        // Affiche l'état de la sortie de veille et du blocage de la mise en veille, et les erreurs.
+        view.connect_events();
+        for error in store_errors.iter() {
+            view.report(error);
+        }
+        if let Some(message) = &config_error {
+            let _ = events.send(controlmod::control::Event::Error { message: message.clone() });
+        }
         
         // Affiche tous les widgets de la fenêtre.
         window.show_all();
@@ -43,6 +202,33 @@ async fn main() {
         view.on_arret_clicked();
     });
 
+    // Arguments de ce lancement ou d'un lancement ultérieur : présente la fenêtre puis exécute les commandes.
+    application.connect_command_line(move |app: &Application, command_line: &ApplicationCommandLine| {
+        app.activate();
+        let args: Vec<String> = command_line.arguments()
+            .iter()
+            .skip(1)
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect();
+        let commands = match climod::cli::forwarded_commands(&args) {
+            Ok(commands) => commands,
+            Err(error) => {
+                command_line.printerr_literal(&format!("[ERROR] {}\n", error));
+                return ExitCode::FAILURE;
+            }
+        };
+        for command in commands {
+            let requests = request_sender.clone();
+            MainContext::default().spawn_local(async move {
+                if let Err(error) = controlmod::control::dispatch(&requests, command).await {
+                    error!(%error, "Forwarded command failed");
+                }
+            });
+        }
+        ExitCode::SUCCESS
+    });
+
     // Exécute l'application.
+    // Une fenêtre masquée compte encore : l'application reste active avec l'icône.
     application.run();
 }
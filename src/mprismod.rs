@@ -0,0 +1,561 @@
+pub mod mpris {
+    use std::collections::HashMap;
+    use tokio::sync::broadcast::{self, error::RecvError};
+    use tracing::{error, info};
+    use zbus::zvariant::{ObjectPath, OwnedValue, Value};
+    use zbus::{dbus_interface, dbus_proxy, fdo, CacheProperties, Connection, ConnectionBuilder};
+    use crate::configmod::config::MprisConfig;
+    use crate::controlmod::control::{self, Command, ControlRequest, Event};
+    use crate::modelmod::model::{AlarmClock, OtherPlayers, RadioStation};
+
+    /// Nom de l'application sur le bus de session.
+    pub const BUS_NAME: &str = "org.mpris.MediaPlayer2.alarm_clock";
+
+    /// Chemin de l'objet imposé par la spécification MPRIS.
+    pub const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+
+    /// Identifiant de la piste en cours ; l'application ne lit qu'une piste à la fois.
+    const TRACK_ID: &str = "/com/my/alarm_clock/track";
+
+    /// Préfixe des noms de bus des lecteurs MPRIS.
+    const MPRIS_PREFIX: &str = "org.mpris.MediaPlayer2.";
+
+    /// Proxy vers un autre lecteur MPRIS.
+    #[dbus_proxy(interface = "org.mpris.MediaPlayer2.Player", default_path = "/org/mpris/MediaPlayer2")]
+    trait OtherPlayer {
+        fn pause(&self) -> zbus::Result<()>;
+
+        fn play(&self) -> zbus::Result<()>;
+
+        #[dbus_proxy(property)]
+        fn playback_status(&self) -> zbus::Result<String>;
+    }
+
+    /// Interface racine `org.mpris.MediaPlayer2`.
+    struct MediaPlayer2;
+
+    #[dbus_interface(name = "org.mpris.MediaPlayer2")]
+    impl MediaPlayer2 {
+        fn raise(&self) {}
+
+        fn quit(&self) {}
+
+        #[dbus_interface(property)]
+        fn can_quit(&self) -> bool {
+            false
+        }
+
+        #[dbus_interface(property)]
+        fn can_raise(&self) -> bool {
+            false
+        }
+
+        #[dbus_interface(property)]
+        fn has_track_list(&self) -> bool {
+            false
+        }
+
+        #[dbus_interface(property)]
+        fn identity(&self) -> String {
+            "Alarm Clock".to_string()
+        }
+
+        #[dbus_interface(property)]
+        fn desktop_entry(&self) -> String {
+            "com.my.alarm_clock".to_string()
+        }
+
+        #[dbus_interface(property)]
+        fn supported_uri_schemes(&self) -> Vec<String> {
+            Vec::new()
+        }
+
+        #[dbus_interface(property)]
+        fn supported_mime_types(&self) -> Vec<String> {
+            Vec::new()
+        }
+    }
+
+    /// Interface `org.mpris.MediaPlayer2.Player`.
+    ///
+    /// Les commandes sont transmises à l'application comme celles des boutons de la fenêtre ;
+    /// l'état affiché est mis à jour à partir des événements diffusés par l'application.
+    pub struct MprisPlayer {
+        requests: async_channel::Sender<ControlRequest>,
+        playing: bool,
+        title: String,
+        station: usize,
+    }
+
+    impl MprisPlayer {
+        /// Crée une nouvelle instance de `MprisPlayer`.
+        ///
+        /// # Parameters
+        ///
+        /// * `requests` - Canal des commandes exécutées par l'application.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `MprisPlayer`, à l'arrêt sur la première station.
+        pub fn new(requests: async_channel::Sender<ControlRequest>) -> Self {
+            MprisPlayer {
+                requests,
+                playing: false,
+                title: String::new(),
+                station: 0,
+            }
+        }
+
+        /// Lance la station d'indice donné (modulo le nombre de stations).
+        async fn play_station(&mut self, index: usize) -> fdo::Result<()> {
+            self.station = index % RadioStation::ALL.len();
+            let station = RadioStation::ALL[self.station].label().to_string();
+            dispatch(&self.requests, Command::PlayRadio { station }).await
+        }
+
+        /// Met à jour l'état à partir d'un événement de l'application.
+        ///
+        /// # Parameters
+        ///
+        /// * `event` - Événement de l'application.
+        ///
+        /// # Returns
+        ///
+        /// `true` si l'état ou les métadonnées ont changé.
+        pub fn update(&mut self, event: &Event) -> bool {
+            let source = match event {
+                Event::PlayerState { playing, source } => {
+                    self.playing = *playing;
+                    source.clone()
+                }
+                Event::AlarmFired { alarm } => {
+                    self.playing = true;
+                    Some(alarm.source.clone())
+                }
+                _ => return false,
+            };
+            if let Some(source) = source {
+                if let Some(index) = RadioStation::from_name(&source)
+                    .and_then(|station: RadioStation| RadioStation::ALL.iter().position(|other: &RadioStation| *other == station))
+                {
+                    self.station = index;
+                }
+                self.title = source;
+            }
+            true
+        }
+    }
+
+    /// Transmet une commande à l'application et convertit l'erreur éventuelle en erreur D-Bus.
+    async fn dispatch(requests: &async_channel::Sender<ControlRequest>, command: Command) -> fdo::Result<()> {
+        control::dispatch(requests, command).await.map(|_| ()).map_err(fdo::Error::Failed)
+    }
+
+    #[dbus_interface(name = "org.mpris.MediaPlayer2.Player")]
+    impl MprisPlayer {
+        async fn next(&mut self) -> fdo::Result<()> {
+            self.play_station(self.station + 1).await
+        }
+
+        async fn previous(&mut self) -> fdo::Result<()> {
+            self.play_station(self.station + RadioStation::ALL.len() - 1).await
+        }
+
+        /// Une radio en direct ne peut pas être mise en pause : la lecture est arrêtée.
+        async fn pause(&self) -> fdo::Result<()> {
+            dispatch(&self.requests, Command::Stop).await
+        }
+
+        async fn play_pause(&mut self) -> fdo::Result<()> {
+            if self.playing {
+                dispatch(&self.requests, Command::Stop).await
+            } else {
+                self.play_station(self.station).await
+            }
+        }
+
+        async fn stop(&self) -> fdo::Result<()> {
+            dispatch(&self.requests, Command::Stop).await
+        }
+
+        async fn play(&mut self) -> fdo::Result<()> {
+            self.play_station(self.station).await
+        }
+
+        fn seek(&self, _offset: i64) {}
+
+        fn set_position(&self, _track_id: ObjectPath<'_>, _position: i64) {}
+
+        fn open_uri(&self, _uri: &str) -> fdo::Result<()> {
+            Err(fdo::Error::NotSupported("OpenUri is not supported".to_string()))
+        }
+
+        #[dbus_interface(property)]
+        fn playback_status(&self) -> String {
+            if self.playing { "Playing" } else { "Stopped" }.to_string()
+        }
+
+        #[dbus_interface(property)]
+        fn metadata(&self) -> HashMap<String, OwnedValue> {
+            let title = if self.title.is_empty() {
+                RadioStation::ALL[self.station].label().to_string()
+            } else {
+                self.title.clone()
+            };
+            let mut metadata = HashMap::new();
+            metadata.insert("mpris:trackid".to_string(), Value::from(ObjectPath::from_static_str_unchecked(TRACK_ID)).into());
+            metadata.insert("xesam:title".to_string(), Value::from(title).into());
+            metadata
+        }
+
+        #[dbus_interface(property)]
+        fn rate(&self) -> f64 {
+            1.0
+        }
+
+        #[dbus_interface(property)]
+        fn minimum_rate(&self) -> f64 {
+            1.0
+        }
+
+        #[dbus_interface(property)]
+        fn maximum_rate(&self) -> f64 {
+            1.0
+        }
+
+        #[dbus_interface(property)]
+        fn volume(&self) -> f64 {
+            1.0
+        }
+
+        #[dbus_interface(property)]
+        fn position(&self) -> i64 {
+            0
+        }
+
+        #[dbus_interface(property)]
+        fn can_go_next(&self) -> bool {
+            true
+        }
+
+        #[dbus_interface(property)]
+        fn can_go_previous(&self) -> bool {
+            true
+        }
+
+        #[dbus_interface(property)]
+        fn can_play(&self) -> bool {
+            true
+        }
+
+        #[dbus_interface(property)]
+        fn can_pause(&self) -> bool {
+            true
+        }
+
+        #[dbus_interface(property)]
+        fn can_seek(&self) -> bool {
+            false
+        }
+
+        #[dbus_interface(property)]
+        fn can_control(&self) -> bool {
+            true
+        }
+    }
+
+    /// Démarre le serveur MPRIS en tâche de fond si la configuration l'active.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - Configuration MPRIS (bus à utiliser).
+    /// * `requests` - Canal des commandes exécutées par l'application.
+    /// * `events` - Diffuseur des événements de l'application.
+    pub fn spawn(config: MprisConfig, requests: async_channel::Sender<ControlRequest>, events: &broadcast::Sender<Event>) {
+        if !config.enabled {
+            return;
+        }
+        let events = events.subscribe();
+        tokio::spawn(async move {
+            if let Err(error) = run(config, requests, events).await {
+                error!(?error, "MPRIS server stopped");
+            }
+        });
+    }
+
+    /// Publie les interfaces MPRIS sur le bus.
+    ///
+    /// # Parameters
+    ///
+    /// * `bus_address` - Adresse d'un bus privé, ou `None` pour le bus de session.
+    /// * `requests` - Canal des commandes exécutées par l'application.
+    ///
+    /// # Returns
+    ///
+    /// La connexion au bus, qui doit rester ouverte tant que le service est publié.
+    pub async fn serve(bus_address: Option<&str>, requests: async_channel::Sender<ControlRequest>) -> zbus::Result<Connection> {
+        let builder = match bus_address {
+            Some(address) => ConnectionBuilder::address(address)?,
+            None => ConnectionBuilder::session()?,
+        };
+        builder
+            .name(BUS_NAME)?
+            .serve_at(OBJECT_PATH, MediaPlayer2)?
+            .serve_at(OBJECT_PATH, MprisPlayer::new(requests))?
+            .build()
+            .await
+    }
+
+    /// Publie les interfaces, signale les changements d'état aux clients et met en pause
+    /// les autres lecteurs pendant les alarmes.
+    async fn run(config: MprisConfig, requests: async_channel::Sender<ControlRequest>, mut events: broadcast::Receiver<Event>) -> zbus::Result<()> {
+        let connection = serve(config.bus_address.as_deref(), requests.clone()).await?;
+        info!(bus_name = BUS_NAME, "MPRIS interface published");
+        let player_ref = connection.object_server().interface::<_, MprisPlayer>(OBJECT_PATH).await?;
+        // Lecteurs mis en pause par les alarmes, et faut-il les relancer à l'arrêt.
+        let mut paused: Vec<String> = Vec::new();
+        let mut resume_paused = false;
+
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            };
+            {
+                let mut player = player_ref.get_mut().await;
+                if player.update(&event) {
+                    player.playback_status_changed(player_ref.signal_context()).await?;
+                    player.metadata_changed(player_ref.signal_context()).await?;
+                }
+            }
+            match &event {
+                Event::AlarmFired { alarm } => {
+                    let policy = other_players_policy(&requests, alarm.alarm_id).await.unwrap_or_default();
+                    if policy.pause {
+                        // Un report suivi d'une nouvelle sonnerie conserve les lecteurs déjà en pause.
+                        for name in pause_others(&connection).await {
+                            if !paused.contains(&name) {
+                                paused.push(name);
+                            }
+                        }
+                        resume_paused = policy.resume;
+                    }
+                }
+                Event::AlarmDismissed { .. } | Event::AlarmTimedOut { .. } => {
+                    if resume_paused {
+                        resume(&connection, &paused).await;
+                    }
+                    paused.clear();
+                    resume_paused = false;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Retourne le comportement vis-à-vis des autres lecteurs configuré pour une alarme.
+    async fn other_players_policy(requests: &async_channel::Sender<ControlRequest>, alarm_id: usize) -> Option<OtherPlayers> {
+        let alarms = control::dispatch(requests, Command::ListAlarms).await.ok()?;
+        serde_json::from_value::<Vec<AlarmClock>>(alarms).ok()?
+            .into_iter()
+            .find(|alarm: &AlarmClock| alarm.a_id == alarm_id)
+            .map(|alarm: AlarmClock| alarm.other_players)
+    }
+
+    /// Crée un proxy vers le lecteur MPRIS portant le nom de bus donné.
+    async fn other_player<'a>(connection: &Connection, name: &'a str) -> zbus::Result<OtherPlayerProxy<'a>> {
+        OtherPlayerProxy::builder(connection)
+            .destination(name)?
+            .cache_properties(CacheProperties::No)
+            .build()
+            .await
+    }
+
+    /// Met en pause tous les autres lecteurs MPRIS en cours de lecture.
+    ///
+    /// # Parameters
+    ///
+    /// * `connection` - Connexion au bus.
+    ///
+    /// # Returns
+    ///
+    /// Les noms de bus des lecteurs mis en pause.
+    pub async fn pause_others(connection: &Connection) -> Vec<String> {
+        let names = match fdo::DBusProxy::new(connection).await {
+            Ok(proxy) => proxy.list_names().await.unwrap_or_default(),
+            Err(error) => {
+                error!(?error, "Failed to list media players");
+                return Vec::new();
+            }
+        };
+        let mut paused = Vec::new();
+        for name in names.iter().map(|name| name.as_str()).filter(|name: &&str| name.starts_with(MPRIS_PREFIX) && *name != BUS_NAME) {
+            let Ok(player) = other_player(connection, name).await else {
+                continue;
+            };
+            if player.playback_status().await.map(|status: String| status == "Playing").unwrap_or(false) {
+                match player.pause().await {
+                    Ok(()) => {
+                        info!(player = name, "Paused media player");
+                        paused.push(name.to_string());
+                    }
+                    Err(error) => error!(player = name, ?error, "Failed to pause media player"),
+                }
+            }
+        }
+        paused
+    }
+
+    /// Relance les lecteurs MPRIS mis en pause par `pause_others`.
+    ///
+    /// # Parameters
+    ///
+    /// * `connection` - Connexion au bus.
+    /// * `names` - Noms de bus des lecteurs à relancer.
+    pub async fn resume(connection: &Connection, names: &[String]) {
+        for name in names.iter() {
+            let result = match other_player(connection, name).await {
+                Ok(player) => player.play().await,
+                Err(error) => Err(error),
+            };
+            match result {
+                Ok(()) => info!(player = %name, "Resumed media player"),
+                Err(error) => error!(player = %name, ?error, "Failed to resume media player"),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::controlmod::control::AlarmInfo;
+        use crate::testmod::fixtures::PrivateBus;
+
+        #[dbus_proxy(
+            interface = "org.mpris.MediaPlayer2.Player",
+            default_service = "org.mpris.MediaPlayer2.alarm_clock",
+            default_path = "/org/mpris/MediaPlayer2"
+        )]
+        trait Player {
+            fn next(&self) -> zbus::Result<()>;
+
+            fn previous(&self) -> zbus::Result<()>;
+
+            #[dbus_proxy(property)]
+            fn playback_status(&self) -> zbus::Result<String>;
+        }
+
+        #[tokio::test]
+        async fn next_and_previous_cycle_stations_on_private_bus() {
+            let bus = PrivateBus::start();
+            let address = bus.address.clone();
+            let (requests, received) = async_channel::unbounded::<ControlRequest>();
+            let _server = serve(Some(&address), requests).await.unwrap();
+            let client = ConnectionBuilder::address(address.as_str()).unwrap().build().await.unwrap();
+            let player = PlayerProxy::new(&client).await.unwrap();
+
+            // Application simulée : enregistre les commandes et répond.
+            let application = tokio::spawn(async move {
+                let mut commands = Vec::new();
+                for _ in 0..3 {
+                    let request = received.recv().await.unwrap();
+                    commands.push(request.command.clone());
+                    let _ = request.reply.send(Ok(serde_json::Value::Null));
+                }
+                commands
+            });
+            player.next().await.unwrap();
+            player.next().await.unwrap();
+            player.previous().await.unwrap();
+
+            let stations: Vec<String> = application.await.unwrap().into_iter()
+                .filter_map(|command: Command| match command {
+                    Command::PlayRadio { station } => Some(station),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(stations, vec!["France Inter", "RTL", "France Inter"]);
+            assert_eq!(player.playback_status().await.unwrap(), "Stopped");
+        }
+
+        /// Lecteur MPRIS minimal simulant une autre application.
+        struct FakePlayer {
+            status: String,
+        }
+
+        #[dbus_interface(name = "org.mpris.MediaPlayer2.Player")]
+        impl FakePlayer {
+            fn pause(&mut self) {
+                self.status = "Paused".to_string();
+            }
+
+            fn play(&mut self) {
+                self.status = "Playing".to_string();
+            }
+
+            #[dbus_interface(property)]
+            fn playback_status(&self) -> String {
+                self.status.clone()
+            }
+        }
+
+        /// Publie un lecteur simulé sur le bus privé.
+        async fn fake_player(address: &str, name: &str, status: &str) -> Connection {
+            ConnectionBuilder::address(address).unwrap()
+                .name(name.to_string()).unwrap()
+                .serve_at(OBJECT_PATH, FakePlayer { status: status.to_string() }).unwrap()
+                .build()
+                .await
+                .unwrap()
+        }
+
+        #[tokio::test]
+        async fn pauses_and_resumes_other_players_on_private_bus() {
+            let bus = PrivateBus::start();
+            let address = bus.address.clone();
+            let (requests, _received) = async_channel::unbounded::<ControlRequest>();
+            let ours = serve(Some(&address), requests).await.unwrap();
+            let _video = fake_player(&address, "org.mpris.MediaPlayer2.video", "Playing").await;
+            let _music = fake_player(&address, "org.mpris.MediaPlayer2.music", "Stopped").await;
+            let client = ConnectionBuilder::address(address.as_str()).unwrap().build().await.unwrap();
+            let status = |name: &'static str| {
+                let client = client.clone();
+                async move { other_player(&client, name).await.unwrap().playback_status().await.unwrap() }
+            };
+
+            let paused = pause_others(&ours).await;
+            assert_eq!(paused, vec!["org.mpris.MediaPlayer2.video".to_string()]);
+            assert_eq!(status("org.mpris.MediaPlayer2.video").await, "Paused");
+            assert_eq!(status("org.mpris.MediaPlayer2.music").await, "Stopped");
+
+            resume(&ours, &paused).await;
+            assert_eq!(status("org.mpris.MediaPlayer2.video").await, "Playing");
+        }
+
+        #[test]
+        fn events_update_status_and_metadata() {
+            let (requests, _received) = async_channel::unbounded();
+            let mut player = MprisPlayer::new(requests);
+
+            assert!(!player.update(&Event::AlarmsChanged));
+            assert!(player.update(&Event::PlayerState { playing: true, source: Some("RTL".to_string()) }));
+            assert_eq!(player.playback_status(), "Playing");
+            assert_eq!(player.station, 2);
+
+            let alarm = AlarmInfo {
+                alarm_id: 1,
+                name: "Travail".to_string(),
+                scheduled: "07:00:00".to_string(),
+                source: "Ma chanson".to_string(),
+            };
+            assert!(player.update(&Event::AlarmFired { alarm }));
+            assert_eq!(player.title, "Ma chanson");
+            assert_eq!(player.station, 2);
+
+            player.update(&Event::PlayerState { playing: false, source: None });
+            assert_eq!(player.playback_status(), "Stopped");
+        }
+    }
+}
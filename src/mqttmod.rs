@@ -0,0 +1,167 @@
+pub mod mqtt {
+    use chrono::Local;
+    use rumqttc::{AsyncClient, Event as MqttEvent, Incoming, LastWill, MqttOptions, Publish, QoS};
+    use serde_json::{json, Value};
+    use tokio::sync::broadcast::{self, error::RecvError};
+    use tokio::time::{interval, sleep, Duration};
+    use tracing::{error, info, warn};
+    use crate::configmod::config::MqttConfig;
+    use crate::controlmod::control::{self, Command, ControlRequest, Event};
+    use crate::modelmod::model::{self, AlarmClock};
+
+    /// Intervalle de republication de l'état (prochaine alarme), en secondes.
+    const STATE_REFRESH_SECS: u64 = 60;
+
+    /// Topics utilisés, tous préfixés par `mqtt.topic_prefix`.
+    struct Topics {
+        prefix: String,
+    }
+
+    impl Topics {
+        /// Retourne le topic complet `<prefix>/<name>`.
+        fn topic(&self, name: &str) -> String {
+            format!("{}/{}", self.prefix, name)
+        }
+
+        /// Retourne le nom de la commande d'un topic `<prefix>/cmd/<commande>`.
+        fn command<'a>(&self, topic: &'a str) -> Option<&'a str> {
+            topic.strip_prefix(&self.prefix)?.strip_prefix("/cmd/")
+        }
+    }
+
+    /// Démarre le client MQTT en tâche de fond si la configuration l'active.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - Configuration du broker et des topics.
+    /// * `requests` - Canal des commandes exécutées par l'application.
+    /// * `events` - Diffuseur des événements de l'application.
+    pub fn spawn(config: MqttConfig, requests: async_channel::Sender<ControlRequest>, events: &broadcast::Sender<Event>) {
+        if !config.enabled {
+            return;
+        }
+        let events = events.subscribe();
+        tokio::spawn(run(config, requests, events));
+    }
+
+    /// Maintient la connexion au broker, publie l'état et les événements, exécute les commandes reçues.
+    ///
+    /// En cas d'erreur, la boucle d'événements de `rumqttc` se reconnecte au prochain appel
+    /// à `poll`, après `mqtt.reconnect_secs` secondes d'attente.
+    async fn run(config: MqttConfig, requests: async_channel::Sender<ControlRequest>, mut events: broadcast::Receiver<Event>) {
+        let topics = Topics { prefix: config.topic_prefix.trim_end_matches('/').to_string() };
+        let mut options = MqttOptions::new(config.client_id.clone(), config.host.clone(), config.port);
+        options.set_keep_alive(Duration::from_secs(30));
+        options.set_last_will(LastWill::new(topics.topic("status"), "offline", QoS::AtLeastOnce, true));
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            options.set_credentials(username.clone(), password.clone());
+        }
+        let (client, mut eventloop) = AsyncClient::new(options, 32);
+        let mut refresh = interval(Duration::from_secs(STATE_REFRESH_SECS));
+
+        loop {
+            tokio::select! {
+                notification = eventloop.poll() => match notification {
+                    Ok(MqttEvent::Incoming(Incoming::ConnAck(_))) => {
+                        info!(host = %config.host, port = config.port, "MQTT connected");
+                        if let Err(error) = client.try_subscribe(topics.topic("cmd/#"), QoS::AtLeastOnce) {
+                            error!(?error, "MQTT subscribe failed");
+                        }
+                        publish(&client, topics.topic("status"), true, "online".to_string());
+                        publish_state(&client, &topics, &requests).await;
+                    }
+                    Ok(MqttEvent::Incoming(Incoming::Publish(message))) => {
+                        handle_command(&client, &topics, &requests, message).await;
+                    }
+                    Ok(_) => {}
+                    Err(error) => {
+                        warn!(?error, retry_secs = config.reconnect_secs, "MQTT connection error, retrying");
+                        sleep(Duration::from_secs(config.reconnect_secs)).await;
+                    }
+                },
+                event = events.recv() => match event {
+                    Ok(event) => publish_event(&client, &topics, &requests, event).await,
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                },
+                _ = refresh.tick() => publish_state(&client, &topics, &requests).await,
+            }
+        }
+    }
+
+    /// Publie un message sans bloquer la boucle d'événements.
+    fn publish(client: &AsyncClient, topic: String, retain: bool, payload: String) {
+        if let Err(error) = client.try_publish(topic, QoS::AtLeastOnce, retain, payload) {
+            error!(?error, "MQTT publish failed");
+        }
+    }
+
+    /// Publie l'état retenu : liste des alarmes et prochaine alarme.
+    async fn publish_state(client: &AsyncClient, topics: &Topics, requests: &async_channel::Sender<ControlRequest>) {
+        let alarms = match control::dispatch(requests, Command::ListAlarms).await {
+            Ok(alarms) => alarms,
+            Err(error) => {
+                error!(%error, "MQTT failed to list alarms");
+                return;
+            }
+        };
+        let next = serde_json::from_value::<Vec<AlarmClock>>(alarms.clone())
+            .ok()
+            .and_then(|alarms: Vec<AlarmClock>| model::next_alarm(&alarms, Local::now()))
+            .map(|(alarm, at)| json!({ "id": alarm.a_id, "name": alarm.name, "at": at }))
+            .unwrap_or(Value::Null);
+        publish(client, topics.topic("alarms"), true, alarms.to_string());
+        publish(client, topics.topic("next_alarm"), true, next.to_string());
+    }
+
+    /// Publie un événement de l'application.
+    async fn publish_event(client: &AsyncClient, topics: &Topics, requests: &async_channel::Sender<ControlRequest>, event: Event) {
+        let payload = serde_json::to_value(&event).unwrap_or(Value::Null).to_string();
+        match event {
+            Event::AlarmFired { .. } => {
+                publish(client, topics.topic("event/fired"), false, payload);
+                publish_state(client, topics, requests).await;
+            }
+            Event::AlarmSnoozed { .. } => publish(client, topics.topic("event/snoozed"), false, payload),
+            Event::AlarmDismissed { .. } => publish(client, topics.topic("event/dismissed"), false, payload),
+            Event::AlarmTimedOut { .. } => publish(client, topics.topic("event/timed_out"), false, payload),
+            Event::AlarmMissed { .. } => publish(client, topics.topic("event/missed"), false, payload),
+            Event::PlaybackFailed { .. } => publish(client, topics.topic("event/failed"), false, payload),
+            Event::TimerFinished { .. } => publish(client, topics.topic("event/timer"), false, payload),
+            Event::DownloadFinished { .. } | Event::DownloadFailed { .. } | Event::StreamFailed { .. } | Event::Error { .. } | Event::WakeArmed { .. } | Event::SleepInhibited { .. } => {}
+            Event::AlarmsChanged => publish_state(client, topics, requests).await,
+            Event::PlayerState { playing, source } => {
+                let station = if playing { source.unwrap_or_default() } else { String::new() };
+                publish(client, topics.topic("playing"), true, station);
+            }
+        }
+    }
+
+    /// Exécute une commande reçue sur `<prefix>/cmd/<commande>`.
+    ///
+    /// * `cmd/play` : nom de la station dans le message.
+    /// * `cmd/stop`, `cmd/snooze`, `cmd/dismiss` : message ignoré.
+    /// * `cmd/toggle` : identifiant de l'alarme dans le message.
+    async fn handle_command(client: &AsyncClient, topics: &Topics, requests: &async_channel::Sender<ControlRequest>, message: Publish) {
+        let payload = String::from_utf8_lossy(&message.payload).trim().to_string();
+        let command = match topics.command(&message.topic) {
+            Some("play") => Command::PlayRadio { station: payload },
+            Some("stop") => Command::Stop,
+            Some("snooze") => Command::Snooze,
+            Some("dismiss") => Command::Dismiss,
+            Some("toggle") => match payload.parse() {
+                Ok(id) => Command::ToggleAlarm { id },
+                Err(_) => {
+                    warn!(payload, "MQTT invalid alarm id");
+                    return;
+                }
+            },
+            _ => return,
+        };
+        info!(?command, "MQTT command");
+        if let Err(error) = control::dispatch_remote(requests, command).await {
+            error!(%error, "MQTT command failed");
+            publish(client, topics.topic("error"), false, error);
+        }
+    }
+}
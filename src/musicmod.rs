@@ -1,11 +1,18 @@
 pub mod music {
     use gstreamer::prelude::*;
-    use gstreamer::{ElementFactory, Pipeline, MessageView, State};
+    use gstreamer::{Element, ElementFactory, Pipeline, MessageView, State};
     use rodio::{Decoder, OutputStream, Sink};
     use std::fs::File;
     use std::io::BufReader;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
     use std::sync::mpsc::{channel, Sender, TryRecvError};
     use std::thread;
+    use tracing::{debug, error, info};
+    use crate::clockmod::clock::Clock;
+    use crate::configmod::config::{AudioBackendKind, AudioConfig};
+    use crate::errormod::error::{Error, Result};
+    pub use crate::playermod::player::{Music, RecordingPlayer};
 
     pub enum MusicCommand {
         Stop,
@@ -13,20 +20,85 @@ pub mod music {
         PlayFile(String),
     }
 
-    pub trait Music {
-        /// Joue une source de musique.
-        ///
-        /// # Parameters
-        ///
-        /// * `source`: Le chemin ou l'URL de la source de musique.
-        fn play(&mut self, source: String);
+    /// Fonction appelée avec l'URL et le message d'erreur quand un flux ne peut pas être lu.
+    pub type ErrorCallback = Arc<dyn Fn(String, String) + Send + Sync>;
 
-        /// Arrête la lecture de la musique.
-        fn stop(&mut self);
+    /// Lecteur de l'un des moteurs audio.
+    pub type Player = Box<dyn Music + Send>;
+
+    /// Crée le lecteur de radio et le lecteur de fichiers du moteur audio configuré.
+    ///
+    /// # Parameters
+    ///
+    /// * `config`: La configuration audio.
+    /// * `clock`: L'horloge de l'application, qui date les commandes du moteur `null`.
+    /// * `on_error`: Appelée avec l'URL et l'erreur quand un flux GStreamer ne peut pas être lu.
+    ///
+    /// # Returns
+    ///
+    /// Le lecteur de radio et le lecteur de fichiers.
+    pub fn players<F>(config: &AudioConfig, clock: Arc<dyn Clock>, on_error: F) -> (Player, Player)
+    where
+        F: Fn(String, String) + Send + Sync + 'static,
+    {
+        let (output, file_output) = match config.backend {
+            AudioBackendKind::Speakers => (AudioOutput::Speakers, AudioOutput::Speakers),
+            AudioBackendKind::Fakesink => (AudioOutput::Discard, AudioOutput::Discard),
+            // Chaque lecteur a son fichier : une chanson ne s'écrit pas par-dessus la radio.
+            AudioBackendKind::Filesink => {
+                let path = PathBuf::from(&config.file);
+                let song_path = song_output(&path);
+                (AudioOutput::File(path), AudioOutput::File(song_path))
+            }
+            AudioBackendKind::Null => {
+                let player = RecordingPlayer::new(clock);
+                return (Box::new(player.clone()), Box::new(player));
+            }
+        };
+        let on_error: ErrorCallback = Arc::new(on_error);
+        let mut radio_player = RadioPlayer::with_output(output.clone());
+        let radio_error = on_error.clone();
+        radio_player.on_error(move |url: String, error: String| radio_error(url, error));
+        if config.backend == AudioBackendKind::Speakers {
+            return (Box::new(radio_player), Box::new(WavPlayer::new()));
+        }
+        // Sans carte son, les fichiers sont aussi décodés par GStreamer.
+        let mut file_player = RadioPlayer::with_output(file_output);
+        file_player.on_error(move |url: String, error: String| on_error(url, error));
+        (Box::new(radio_player), Box::new(file_player))
+    }
+
+    /// Retourne le fichier écrit par le lecteur de fichiers du moteur `filesink` : `audio.song.wav` pour `audio.wav`.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Le fichier écrit par le lecteur de radio.
+    pub fn song_output(path: &Path) -> PathBuf {
+        let stem = path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+        let name = match path.extension() {
+            Some(extension) => format!("{}.song.{}", stem, extension.to_string_lossy()),
+            None => format!("{}.song", stem),
+        };
+        path.with_file_name(name)
+    }
+
+    /// Sortie du pipeline GStreamer de `RadioPlayer`.
+    #[derive(Clone, Debug)]
+    pub enum AudioOutput {
+        /// La carte son (`autoaudiosink`).
+        Speakers,
+        /// Aucune sortie (`fakesink`) : le flux est décodé puis ignoré.
+        Discard,
+        /// Un fichier WAV (`wavenc ! filesink`), pour vérifier ce qui a été décodé.
+        File(PathBuf),
     }
 
     pub struct RadioPlayer {
         sender: Option<Sender<MusicCommand>>,
+        on_error: Option<ErrorCallback>,
+        /// Erreur d'initialisation de GStreamer, renvoyée à chaque lecture.
+        init_error: Option<String>,
+        output: AudioOutput,
     }
 
     pub struct WavPlayer {
@@ -34,73 +106,137 @@ pub mod music {
     }
 
     impl RadioPlayer {
-        /// Crée une nouvelle instance de `RadioPlayer`.
+        /// Crée une nouvelle instance de `RadioPlayer`, qui joue sur la carte son.
         ///
         /// # Returns
         ///
-        /// Une nouvelle instance de `RadioPlayer`.
+        /// Une nouvelle instance de `RadioPlayer`, même si GStreamer n'a pas pu être initialisé.
         pub fn new() -> Self {
-            gstreamer::init().unwrap();
+            RadioPlayer::with_output(AudioOutput::Speakers)
+        }
+
+        /// Crée une nouvelle instance de `RadioPlayer` qui décode réellement les flux vers la sortie donnée.
+        ///
+        /// # Parameters
+        ///
+        /// * `output`: La sortie du pipeline ; `Discard` et `File` ne demandent pas de carte son.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `RadioPlayer`, même si GStreamer n'a pas pu être initialisé.
+        pub fn with_output(output: AudioOutput) -> Self {
+            let init_error = gstreamer::init().err().map(|error| {
+                error!(?error, "Failed to initialize GStreamer");
+                error.to_string()
+            });
             RadioPlayer {
                 sender: None,
+                on_error: None,
+                init_error,
+                output,
             }
         }
 
+        /// Définit la fonction appelée quand la lecture d'un flux échoue.
+        ///
+        /// # Parameters
+        ///
+        /// * `callback`: La fonction, appelée depuis le fil de lecture avec l'URL et l'erreur.
+        pub fn on_error<F>(&mut self, callback: F)
+        where
+            F: Fn(String, String) + Send + Sync + 'static,
+        {
+            self.on_error = Some(Arc::new(callback));
+        }
+
         /// Démarre la lecture de la musique en fonction de la commande donnée.
         ///
         /// # Parameters
         ///
         /// * `command`: La commande de lecture de musique (URL ou fichier).
-        fn start(&mut self, command: MusicCommand) {
+        ///
+        /// # Returns
+        ///
+        /// `Result<()>` - `Error::Playback` si GStreamer ou l'un de ses éléments est indisponible.
+        fn start(&mut self, command: MusicCommand) -> Result<()> {
+            if let Some(error) = &self.init_error {
+                return Err(Error::Playback(format!("GStreamer : {}", error)));
+            }
+            let pipeline = Pipeline::new(None);
+            let uridecodebin = make_element("uridecodebin")?;
+            let audioconvert = make_element("audioconvert")?;
+            let audioresample = make_element("audioresample")?;
+            let sink = output_elements(&self.output)?;
+            let mut chain = vec![audioconvert.clone(), audioresample];
+            chain.extend(sink);
             if let Some(sender) = &self.sender {
                 let _ = sender.send(MusicCommand::Stop);
             }
             let (sender, receiver) = channel();
             self.sender = Some(sender.clone());
-            let pipeline = Pipeline::new(None);
-            let uridecodebin = ElementFactory::make("uridecodebin", None).unwrap();
-            let audioconvert = ElementFactory::make("audioconvert", None).unwrap();
-            let audioresample = ElementFactory::make("audioresample", None).unwrap();
-            let autoaudiosink = ElementFactory::make("autoaudiosink", None).unwrap();
+            let on_error = self.on_error.clone();
+            let source = match &command {
+                MusicCommand::PlayUrl(url) => url.clone(),
+                _ => String::new(),
+            };
+            let report = move |error: String| {
+                if let Some(callback) = &on_error {
+                    callback(source.clone(), error);
+                }
+            };
 
             thread::spawn(move || {
                 match command {
                     MusicCommand::PlayUrl(url) => {
-                        if pipeline.add_many(&[&uridecodebin, &audioconvert, &audioresample, &autoaudiosink]).is_err() {
-                            eprintln!("Failed to add elements to pipeline");
+                        let chain: Vec<&Element> = chain.iter().collect();
+                        if pipeline.add(&uridecodebin).is_err() || pipeline.add_many(&chain).is_err() {
+                            error!("Failed to add elements to pipeline");
                             return;
                         }
-                        if gstreamer::Element::link_many(&[&audioconvert, &audioresample, &autoaudiosink]).is_err() {
-                            eprintln!("Failed to link elements in pipeline");
+                        if gstreamer::Element::link_many(&chain).is_err() {
+                            error!("Failed to link elements in pipeline");
                             return;
                         }
 
                         uridecodebin.connect_pad_added(move |_element, src_pad| {
-                            let sink_pad = audioconvert.static_pad("sink").unwrap();
-                            if src_pad.link(&sink_pad).is_err() {
-                                eprintln!("Failed to link src pad to sink pad");
+                            match audioconvert.static_pad("sink") {
+                                Some(sink_pad) => {
+                                    if src_pad.link(&sink_pad).is_err() {
+                                        error!("Failed to link src pad to sink pad");
+                                    }
+                                }
+                                None => error!("Failed to get audioconvert sink pad"),
                             }
                         });
 
                         if uridecodebin.set_property("uri", &url).is_err() {
-                            eprintln!("Failed to set URI for URL");
+                            error!(%url, "Failed to set URI for URL");
                             return;
                         }
 
+                        info!(%url, "Playing stream");
                         if pipeline.set_state(State::Playing).is_err() {
-                            eprintln!("Failed to set pipeline state to Playing");
+                            error!(%url, "Failed to set pipeline state to Playing");
+                            report("Failed to set pipeline state to Playing".to_string());
                             return;
                         }
                     }
                     _ => {}
                 }
-                let bus = pipeline.bus().unwrap();
+                let bus = match pipeline.bus() {
+                    Some(bus) => bus,
+                    None => {
+                        report("Pipeline without bus".to_string());
+                        return;
+                    }
+                };
 
                 loop {
                     match receiver.try_recv() {
                         Ok(MusicCommand::Stop) | Err(TryRecvError::Disconnected) => {
+                            info!("Stopping stream");
                             if pipeline.set_state(State::Null).is_err() {
-                                eprintln!("Failed to set pipeline state to Null");
+                                error!("Failed to set pipeline state to Null");
                             }
                             break;
                         }
@@ -110,14 +246,22 @@ pub mod music {
 
                     for msg in bus.iter_timed(gstreamer::ClockTime::from_seconds(1)) {
                         match msg.view() {
-                            MessageView::Eos(..) => break,
+                            MessageView::Eos(..) => {
+                                info!("End of stream");
+                                break;
+                            }
+                            // Seuls les changements d'état du pipeline sont journalisés, pas ceux de chaque élément.
+                            MessageView::StateChanged(state) if state.src().map(|s| s == pipeline).unwrap_or(false) => {
+                                debug!(old = ?state.old(), new = ?state.current(), pending = ?state.pending(), "Pipeline state changed");
+                            }
                             MessageView::Error(err) => {
-                                eprintln!(
-                                    "Error from {:?}: {} ({:?})",
-                                    err.src().map(|s| s.path_string()),
-                                    err.error(),
-                                    err.debug()
+                                error!(
+                                    source = ?err.src().map(|s| s.path_string()),
+                                    error = %err.error(),
+                                    debug = ?err.debug(),
+                                    "Stream error"
                                 );
+                                report(err.error().to_string());
                                 break;
                             }
                             _ => (),
@@ -125,6 +269,59 @@ pub mod music {
                     }
                 }
             });
+            Ok(())
+        }
+    }
+
+    /// Crée un élément GStreamer.
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: Le nom de la fabrique de l'élément.
+    ///
+    /// # Returns
+    ///
+    /// `Result<Element>` - L'élément, ou `Error::Playback` si le greffon correspondant est absent.
+    fn make_element(name: &str) -> Result<Element> {
+        ElementFactory::make(name, None).map_err(|error| Error::Playback(format!("{} : {}", name, error)))
+    }
+
+    /// Transforme le chemin d'un fichier local en URI `file://` ; les URL sont laissées telles quelles.
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: L'URL ou le chemin.
+    ///
+    /// # Returns
+    ///
+    /// L'URI à donner à `uridecodebin`.
+    fn to_uri(source: String) -> String {
+        if source.contains("://") {
+            return source;
+        }
+        let path = std::fs::canonicalize(&source).unwrap_or_else(|_| PathBuf::from(&source));
+        format!("file://{}", path.display())
+    }
+
+    /// Crée les derniers éléments du pipeline, après la conversion du son.
+    ///
+    /// # Parameters
+    ///
+    /// * `output`: La sortie choisie.
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Element>>` - Les éléments à lier dans l'ordre, ou `Error::Playback` si un greffon est absent.
+    fn output_elements(output: &AudioOutput) -> Result<Vec<Element>> {
+        match output {
+            AudioOutput::Speakers => Ok(vec![make_element("autoaudiosink")?]),
+            AudioOutput::Discard => Ok(vec![make_element("fakesink")?]),
+            AudioOutput::File(path) => {
+                let filesink = make_element("filesink")?;
+                filesink.set_property("location", &path.to_string_lossy().to_string())
+                    .map_err(|error| Error::Playback(format!("filesink : {}", error)))?;
+                Ok(vec![make_element("wavenc")?, filesink])
+            }
         }
     }
 
@@ -145,30 +342,53 @@ pub mod music {
         /// # Parameters
         ///
         /// * `command`: La commande de lecture de musique (URL ou fichier).
-        fn start(&mut self, command: MusicCommand) {
+        ///
+        /// # Returns
+        ///
+        /// `Result<()>` - `Error::Playback` si le fichier est illisible ou si aucune sortie audio n'est disponible.
+        fn start(&mut self, command: MusicCommand) -> Result<()> {
+            let (file_path, source) = match command {
+                MusicCommand::PlayFile(file_path) => {
+                    let file = File::open(&file_path).map_err(|error| Error::Playback(format!("{} : {}", file_path, error)))?;
+                    let source = Decoder::new(BufReader::new(file)).map_err(|error| Error::Playback(format!("{} : {}", file_path, error)))?;
+                    (file_path, Some(source))
+                }
+                _ => (String::new(), None),
+            };
             if let Some(sender) = &self.sender {
                 let _ = sender.send(MusicCommand::Stop);
             }
             let (sender, receiver) = channel();
             self.sender = Some(sender.clone());
+            // La sortie audio est ouverte dans le fil de lecture, qui signale si elle est disponible.
+            let (started, startup) = channel();
 
             thread::spawn(move || {
-                let (_stream, stream_handle) = OutputStream::try_default().unwrap();
-                let sink = Sink::try_new(&stream_handle).unwrap();
-
-                match command {
-                    MusicCommand::PlayFile(file_path) => {
-                        println!("[INFO] play music");
-                        let file = BufReader::new(File::open(file_path).unwrap());
-                        let source = Decoder::new(file).unwrap();
-                        sink.append(source);
+                let (_stream, stream_handle) = match OutputStream::try_default() {
+                    Ok(output) => output,
+                    Err(error) => {
+                        let _ = started.send(Err(Error::Playback(format!("sortie audio : {}", error))));
+                        return;
                     }
-                    _ => {}
+                };
+                let sink = match Sink::try_new(&stream_handle) {
+                    Ok(sink) => sink,
+                    Err(error) => {
+                        let _ = started.send(Err(Error::Playback(format!("sortie audio : {}", error))));
+                        return;
+                    }
+                };
+
+                if let Some(source) = source {
+                    info!(path = %file_path, "Playing file");
+                    sink.append(source);
                 }
+                let _ = started.send(Ok(()));
 
                 loop {
                     match receiver.try_recv() {
                         Ok(MusicCommand::Stop) | Err(TryRecvError::Disconnected) => {
+                            info!(path = %file_path, "Stopping file");
                             sink.stop();
                             break;
                         }
@@ -177,6 +397,7 @@ pub mod music {
                     }
                 }
             });
+            startup.recv().unwrap_or_else(|_| Err(Error::Playback("le fil de lecture s'est arrêté".to_string())))
         }
     }
 
@@ -185,9 +406,9 @@ pub mod music {
         ///
         /// # Parameters
         ///
-        /// * `url`: L'URL de la musique à jouer.
-        fn play(&mut self, url: String) {
-            self.start(MusicCommand::PlayUrl(url));
+        /// * `url`: L'URL de la musique à jouer, ou le chemin d'un fichier local.
+        fn play(&mut self, url: String) -> Result<()> {
+            self.start(MusicCommand::PlayUrl(to_uri(url)))
         }
 
         /// Arrête la lecture de la musique.
@@ -204,8 +425,8 @@ pub mod music {
         /// # Parameters
         ///
         /// * `file_path`: Le chemin du fichier de musique à jouer.
-        fn play(&mut self, file_path: String) {
-            self.start(MusicCommand::PlayFile(file_path));
+        fn play(&mut self, file_path: String) -> Result<()> {
+            self.start(MusicCommand::PlayFile(file_path))
         }
 
         /// Arrête la lecture de la musique.
@@ -215,4 +436,63 @@ pub mod music {
             }
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+        use std::time::{Duration, Instant};
+
+        #[test]
+        fn filesink_players_write_separate_files() {
+            assert_eq!(song_output(Path::new("ser/audio.wav")), PathBuf::from("ser/audio.song.wav"));
+            assert_eq!(song_output(Path::new("audio")), PathBuf::from("audio.song"));
+        }
+
+        /// Écrit un fichier WAV de `samples` échantillons silencieux, mono 16 bits à 8 kHz.
+        fn write_silence(path: &std::path::Path, samples: u32) {
+            let data_len = samples * 2;
+            let mut wav = Vec::new();
+            wav.extend_from_slice(b"RIFF");
+            wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+            wav.extend_from_slice(b"WAVEfmt ");
+            wav.extend_from_slice(&16u32.to_le_bytes());
+            wav.extend_from_slice(&1u16.to_le_bytes());
+            wav.extend_from_slice(&1u16.to_le_bytes());
+            wav.extend_from_slice(&8000u32.to_le_bytes());
+            wav.extend_from_slice(&16000u32.to_le_bytes());
+            wav.extend_from_slice(&2u16.to_le_bytes());
+            wav.extend_from_slice(&16u16.to_le_bytes());
+            wav.extend_from_slice(b"data");
+            wav.extend_from_slice(&data_len.to_le_bytes());
+            wav.resize(wav.len() + data_len as usize, 0);
+            fs::write(path, wav).unwrap();
+        }
+
+        #[test]
+        fn file_output_decodes_without_sound_card() {
+            let dir = tempfile::tempdir().unwrap();
+            let input = dir.path().join("input.wav");
+            let output = dir.path().join("output.wav");
+            write_silence(&input, 8000);
+
+            let mut player = RadioPlayer::with_output(AudioOutput::File(output.clone()));
+            player.play(format!("file://{}", input.display()))
+                .unwrap_or_else(|error| panic!("the GStreamer base and good plugins are required by this test: {}", error));
+            // Le fichier est complet quand sa taille ne change plus.
+            let deadline = Instant::now() + Duration::from_secs(10);
+            let mut size = 0;
+            while Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(200));
+                let current = fs::metadata(&output).map(|metadata| metadata.len()).unwrap_or(0);
+                if current > 44 && current == size {
+                    break;
+                }
+                size = current;
+            }
+            player.stop();
+
+            assert!(size > 44, "nothing decoded into {}", output.display());
+        }
+    }
 }
@@ -0,0 +1,314 @@
+pub mod notify {
+    use chrono::{DateTime, Duration as ChronoDuration, Local};
+    use futures::StreamExt;
+    use std::collections::HashMap;
+    use tokio::sync::broadcast::{self, error::RecvError};
+    use tokio::time::{interval, Duration};
+    use tracing::{error, info};
+    use zbus::zvariant::Value;
+    use zbus::{dbus_proxy, Connection, ConnectionBuilder};
+    use crate::configmod::config::NotificationsConfig;
+    use crate::controlmod::control::{self, AlarmInfo, Command, ControlRequest, Event};
+    use crate::modelmod::model::AlarmClock;
+
+    const APP_NAME: &str = "Alarm Clock";
+
+    /// Intervalle de recherche des alarmes à rappeler, en secondes.
+    const REMINDER_CHECK_SECS: u64 = 30;
+
+    /// Urgence « critique » : la notification reste affichée jusqu'à une action.
+    const URGENCY_CRITICAL: u8 = 2;
+    const URGENCY_NORMAL: u8 = 1;
+
+    /// Proxy du service de notifications de bureau.
+    #[dbus_proxy(
+        interface = "org.freedesktop.Notifications",
+        default_service = "org.freedesktop.Notifications",
+        default_path = "/org/freedesktop/Notifications"
+    )]
+    trait Notifications {
+        #[allow(clippy::too_many_arguments)]
+        fn notify(
+            &self,
+            app_name: &str,
+            replaces_id: u32,
+            app_icon: &str,
+            summary: &str,
+            body: &str,
+            actions: &[&str],
+            hints: HashMap<&str, Value<'_>>,
+            expire_timeout: i32,
+        ) -> zbus::Result<u32>;
+
+        fn close_notification(&self, id: u32) -> zbus::Result<()>;
+
+        #[dbus_proxy(signal)]
+        fn action_invoked(&self, id: u32, action_key: &str) -> zbus::Result<()>;
+    }
+
+    /// Démarre les notifications de bureau en tâche de fond si la configuration les active.
+    ///
+    /// Sans bus de session (serveur, `--headless` sous systemd), une erreur est journalisée
+    /// et l'application continue sans notifications.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - Configuration des notifications.
+    /// * `requests` - Canal des commandes exécutées par l'application (actions Répéter et Arrêter).
+    /// * `events` - Diffuseur des événements de l'application.
+    pub fn spawn(config: NotificationsConfig, requests: async_channel::Sender<ControlRequest>, events: &broadcast::Sender<Event>) {
+        if !config.enabled {
+            return;
+        }
+        let events = events.subscribe();
+        tokio::spawn(async move {
+            if let Err(error) = run(config, requests, events).await {
+                error!(?error, "Desktop notifications unavailable");
+            }
+        });
+    }
+
+    /// Affiche les notifications des événements, des rappels, et exécute les actions cliquées.
+    async fn run(config: NotificationsConfig, requests: async_channel::Sender<ControlRequest>, mut events: broadcast::Receiver<Event>) -> zbus::Result<()> {
+        let connection = match config.bus_address.as_deref() {
+            Some(address) => ConnectionBuilder::address(address)?.build().await?,
+            None => Connection::session().await?,
+        };
+        let proxy = NotificationsProxy::new(&connection).await?;
+        let mut actions = proxy.receive_action_invoked().await?;
+        let mut reminders = interval(Duration::from_secs(REMINDER_CHECK_SECS));
+        let mut last_reminder_check = Local::now();
+        // Notification de l'alarme qui sonne, fermée à l'arrêt ou au report.
+        let mut ringing: Option<u32> = None;
+        info!("Desktop notifications enabled");
+
+        loop {
+            tokio::select! {
+                event = events.recv() => match event {
+                    Ok(event) => handle_event(&proxy, &mut ringing, event).await,
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                },
+                Some(signal) = actions.next() => {
+                    let Ok(args) = signal.args() else {
+                        continue;
+                    };
+                    if ringing != Some(*args.id()) {
+                        continue;
+                    }
+                    // Mêmes chemins que les boutons Répéter et Arrêter de l'interface.
+                    let command = match *args.action_key() {
+                        "snooze" => Command::Snooze,
+                        "dismiss" => Command::Dismiss,
+                        _ => continue,
+                    };
+                    if let Err(error) = control::dispatch(&requests, command).await {
+                        error!(%error, "Notification action failed");
+                    }
+                }
+                _ = reminders.tick(), if config.reminder_minutes > 0 => {
+                    let now = Local::now();
+                    remind(&proxy, &requests, config.reminder_minutes, last_reminder_check, now).await;
+                    last_reminder_check = now;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Affiche une notification.
+    ///
+    /// # Returns
+    ///
+    /// L'identifiant de la notification, ou `None` en cas d'erreur.
+    async fn notify(proxy: &NotificationsProxy<'_>, summary: &str, body: &str, actions: &[&str], urgency: u8) -> Option<u32> {
+        let mut hints: HashMap<&str, Value<'_>> = HashMap::new();
+        hints.insert("urgency", Value::U8(urgency));
+        // Les notifications critiques n'expirent pas ; -1 laisse le serveur choisir.
+        let expire_timeout = if urgency == URGENCY_CRITICAL { 0 } else { -1 };
+        match proxy.notify(APP_NAME, 0, "alarm-symbolic", summary, body, actions, hints, expire_timeout).await {
+            Ok(id) => Some(id),
+            Err(error) => {
+                error!(?error, "Failed to show notification");
+                None
+            }
+        }
+    }
+
+    /// Retourne le titre d'une notification d'alarme.
+    fn alarm_title(alarm: &AlarmInfo) -> String {
+        if alarm.name.is_empty() {
+            format!("Alarme de {}", alarm.scheduled)
+        } else {
+            alarm.name.clone()
+        }
+    }
+
+    /// Affiche la notification correspondant à un événement.
+    async fn handle_event(proxy: &NotificationsProxy<'_>, ringing: &mut Option<u32>, event: Event) {
+        match event {
+            Event::AlarmFired { alarm } => {
+                let body = format!("{} - {}", alarm.scheduled, alarm.source);
+                *ringing = notify(proxy, &alarm_title(&alarm), &body, &["snooze", "Répéter", "dismiss", "Arrêter"], URGENCY_CRITICAL).await;
+            }
+            Event::AlarmSnoozed { alarm, until } => {
+                close(proxy, ringing).await;
+                let body = format!("Nouvelle sonnerie à {}", until.format("%H:%M"));
+                notify(proxy, &alarm_title(&alarm), &body, &[], URGENCY_NORMAL).await;
+            }
+            Event::AlarmDismissed { .. } | Event::AlarmTimedOut { .. } => close(proxy, ringing).await,
+            Event::PlaybackFailed { alarm } => {
+                let body = format!("Impossible de lire {}", alarm.source);
+                notify(proxy, &alarm_title(&alarm), &body, &[], URGENCY_CRITICAL).await;
+            }
+            Event::StreamFailed { source, error } => {
+                let body = format!("{} : {}", source, error);
+                notify(proxy, "Échec de lecture de la radio", &body, &[], URGENCY_NORMAL).await;
+            }
+            Event::Error { message } => {
+                notify(proxy, "Erreur du réveil", &message, &[], URGENCY_NORMAL).await;
+            }
+            Event::DownloadFinished { alarm } => {
+                notify(proxy, "Téléchargement terminé", &alarm.source, &[], URGENCY_NORMAL).await;
+            }
+            Event::DownloadFailed { alarm } => {
+                let body = format!("La chanson de l'alarme {} n'a pas pu être téléchargée", alarm_title(&alarm));
+                notify(proxy, "Échec du téléchargement", &body, &[], URGENCY_NORMAL).await;
+            }
+            Event::TimerFinished { name, .. } => {
+                notify(proxy, "Minuteur terminé", &name, &[], URGENCY_CRITICAL).await;
+            }
+            _ => {}
+        }
+    }
+
+    /// Ferme la notification de l'alarme qui sonnait.
+    async fn close(proxy: &NotificationsProxy<'_>, ringing: &mut Option<u32>) {
+        if let Some(id) = ringing.take() {
+            let _ = proxy.close_notification(id).await;
+        }
+    }
+
+    /// Affiche un rappel pour chaque alarme qui sonnera dans `minutes` minutes.
+    ///
+    /// Les alarmes dont le rappel tombe entre `last_check` (exclu) et `now` (inclus) sont rappelées.
+    async fn remind(proxy: &NotificationsProxy<'_>, requests: &async_channel::Sender<ControlRequest>, minutes: u32, last_check: DateTime<Local>, now: DateTime<Local>) {
+        let alarms = match control::dispatch(requests, Command::ListAlarms).await {
+            Ok(alarms) => serde_json::from_value::<Vec<AlarmClock>>(alarms).unwrap_or_default(),
+            Err(error) => {
+                error!(%error, "Reminders failed to list alarms");
+                return;
+            }
+        };
+        let lead = ChronoDuration::minutes(minutes as i64);
+        for alarm in alarms.iter().filter(|alarm: &&AlarmClock| alarm.active) {
+            if let Some(at) = alarm.occurs_between(last_check + lead, now + lead) {
+                let info = AlarmInfo::new(alarm);
+                let body = format!("Sonnera à {} ({})", at.format("%H:%M"), info.source);
+                notify(proxy, &format!("Rappel : {}", alarm_title(&info)), &body, &[], URGENCY_NORMAL).await;
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::sync::{Arc, Mutex};
+        use zbus::dbus_interface;
+        use zbus::zvariant::OwnedValue;
+        use crate::testmod::fixtures::PrivateBus;
+
+        const NOTIFICATION_ID: u32 = 7;
+
+        /// Notification reçue par le faux service : titre, texte et actions.
+        type Shown = (String, String, Vec<String>);
+
+        /// Service de notifications simulé, qui enregistre les notifications affichées.
+        struct FakeNotifications {
+            shown: Arc<Mutex<Vec<Shown>>>,
+        }
+
+        #[dbus_interface(name = "org.freedesktop.Notifications")]
+        impl FakeNotifications {
+            #[allow(clippy::too_many_arguments)]
+            fn notify(
+                &self,
+                _app_name: String,
+                _replaces_id: u32,
+                _app_icon: String,
+                summary: String,
+                body: String,
+                actions: Vec<String>,
+                _hints: HashMap<String, OwnedValue>,
+                _expire_timeout: i32,
+            ) -> u32 {
+                self.shown.lock().unwrap().push((summary, body, actions));
+                NOTIFICATION_ID
+            }
+
+            fn close_notification(&self, _id: u32) {}
+        }
+
+        fn info() -> AlarmInfo {
+            AlarmInfo {
+                alarm_id: 0,
+                name: "Travail".to_string(),
+                scheduled: "07:00:00".to_string(),
+                source: "France Inter".to_string(),
+            }
+        }
+
+        /// Attend la prochaine commande transmise à l'application et y répond.
+        async fn next_command(received: &async_channel::Receiver<ControlRequest>) -> Command {
+            let request = tokio::time::timeout(Duration::from_secs(5), received.recv()).await.unwrap().unwrap();
+            let _ = request.reply.send(Ok(serde_json::Value::Null));
+            request.command
+        }
+
+        #[tokio::test]
+        async fn alarm_notification_actions_snooze_and_dismiss() {
+            let bus = PrivateBus::start();
+            let shown = Arc::new(Mutex::new(Vec::new()));
+            let server = ConnectionBuilder::address(bus.address.as_str()).unwrap()
+                .name("org.freedesktop.Notifications").unwrap()
+                .serve_at("/org/freedesktop/Notifications", FakeNotifications { shown: shown.clone() }).unwrap()
+                .build()
+                .await
+                .unwrap();
+            let config = NotificationsConfig {
+                enabled: true,
+                reminder_minutes: 0,
+                bus_address: Some(bus.address.clone()),
+            };
+            let (requests, received) = async_channel::unbounded::<ControlRequest>();
+            let (events, _) = broadcast::channel(16);
+            let service = tokio::spawn(run(config, requests, events.subscribe()));
+
+            // La notification n'est affichée qu'une fois le service abonné aux actions.
+            for _ in 0..250 {
+                events.send(Event::AlarmFired { alarm: info() }).unwrap();
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                if !shown.lock().unwrap().is_empty() {
+                    break;
+                }
+            }
+            let (summary, body, actions) = shown.lock().unwrap()[0].clone();
+            assert_eq!(summary, "Travail");
+            assert_eq!(body, "07:00:00 - France Inter");
+            assert_eq!(actions, vec!["snooze", "Répéter", "dismiss", "Arrêter"]);
+
+            let path = "/org/freedesktop/Notifications";
+            let interface = "org.freedesktop.Notifications";
+            server.emit_signal(None::<()>, path, interface, "ActionInvoked", &(NOTIFICATION_ID, "snooze")).await.unwrap();
+            assert!(matches!(next_command(&received).await, Command::Snooze));
+            server.emit_signal(None::<()>, path, interface, "ActionInvoked", &(NOTIFICATION_ID, "dismiss")).await.unwrap();
+            assert!(matches!(next_command(&received).await, Command::Dismiss));
+
+            // Une action d'une autre notification est ignorée.
+            server.emit_signal(None::<()>, path, interface, "ActionInvoked", &(NOTIFICATION_ID + 1, "dismiss")).await.unwrap();
+            assert!(tokio::time::timeout(Duration::from_millis(300), received.recv()).await.is_err());
+
+            service.abort();
+        }
+    }
+}
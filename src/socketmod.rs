@@ -0,0 +1,105 @@
+pub mod socket {
+    use serde_json::{json, Value};
+    use std::io;
+    use std::path::PathBuf;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::unix::OwnedWriteHalf;
+    use tokio::net::{UnixListener, UnixStream};
+    use tokio::sync::broadcast::{self, error::RecvError};
+    use tracing::{error, info};
+    use crate::controlmod::control::{self, Command, ControlRequest, Event};
+
+    /// Retourne le chemin de la socket de contrôle.
+    ///
+    /// # Returns
+    ///
+    /// `$XDG_RUNTIME_DIR/alarm_clock.sock`, ou `ser/control.sock` si la variable n'est pas définie.
+    pub fn socket_path() -> PathBuf {
+        match std::env::var_os("XDG_RUNTIME_DIR") {
+            Some(dir) => PathBuf::from(dir).join("alarm_clock.sock"),
+            None => PathBuf::from("ser/control.sock"),
+        }
+    }
+
+    /// Démarre la socket de contrôle en tâche de fond.
+    ///
+    /// # Parameters
+    ///
+    /// * `requests` - Canal des commandes exécutées par l'application.
+    /// * `events` - Diffuseur des événements de l'application.
+    pub fn spawn(requests: async_channel::Sender<ControlRequest>, events: broadcast::Sender<Event>) {
+        tokio::spawn(async move {
+            if let Err(error) = serve(requests, events).await {
+                error!(?error, "Control socket stopped");
+            }
+        });
+    }
+
+    /// Accepte les connexions sur la socket de contrôle.
+    async fn serve(requests: async_channel::Sender<ControlRequest>, events: broadcast::Sender<Event>) -> io::Result<()> {
+        let path = socket_path();
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path)?;
+        info!(path = %path.display(), "Control socket listening");
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let requests = requests.clone();
+            let events = events.clone();
+            tokio::spawn(async move {
+                if let Err(error) = handle_client(stream, requests, events).await {
+                    error!(?error, "Control client error");
+                }
+            });
+        }
+    }
+
+    /// Traite les requêtes d'un client, une requête JSON par ligne.
+    ///
+    /// `{"method": "subscribe"}` transforme la connexion en flux d'événements.
+    async fn handle_client(stream: UnixStream, requests: async_channel::Sender<ControlRequest>, events: broadcast::Sender<Event>) -> io::Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        while let Some(line) = lines.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut request: Value = match serde_json::from_str(&line) {
+                Ok(request) => request,
+                Err(error) => {
+                    write_line(&mut writer, &json!({ "id": null, "error": format!("Invalid JSON: {}", error) })).await?;
+                    continue;
+                }
+            };
+            let id = request.as_object_mut().and_then(|object| object.remove("id")).unwrap_or(Value::Null);
+            if request.get("method").and_then(Value::as_str) == Some("subscribe") {
+                write_line(&mut writer, &json!({ "id": id, "result": "subscribed" })).await?;
+                return stream_events(writer, events.subscribe()).await;
+            }
+            let response = match serde_json::from_value::<Command>(request) {
+                Ok(command) => match control::dispatch_remote(&requests, command).await {
+                    Ok(result) => json!({ "id": id, "result": result }),
+                    Err(error) => json!({ "id": id, "error": error }),
+                },
+                Err(error) => json!({ "id": id, "error": format!("Invalid request: {}", error) }),
+            };
+            write_line(&mut writer, &response).await?;
+        }
+        Ok(())
+    }
+
+    /// Envoie les événements de l'application au client jusqu'à sa déconnexion.
+    async fn stream_events(mut writer: OwnedWriteHalf, mut events: broadcast::Receiver<Event>) -> io::Result<()> {
+        loop {
+            match events.recv().await {
+                Ok(event) => write_line(&mut writer, &serde_json::to_value(&event)?).await?,
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return Ok(()),
+            }
+        }
+    }
+
+    /// Écrit une valeur JSON suivie d'un retour à la ligne.
+    async fn write_line(writer: &mut OwnedWriteHalf, value: &Value) -> io::Result<()> {
+        writer.write_all(format!("{}\n", value).as_bytes()).await
+    }
+}
@@ -0,0 +1,53 @@
+pub mod fixtures {
+    use chrono::{DateTime, Local, TimeZone};
+    use std::io::{BufRead, BufReader};
+    use std::process::{Child, Command, Stdio};
+    use crate::modelmod::model::{AlarmClock, RadioStation};
+
+    /// Alarme radio « Travail » de tous les jours, d'identifiant 0.
+    pub fn alarm(hour: u8, minute: u8) -> AlarmClock {
+        AlarmClock::new(0, "Travail".to_string(), hour, minute, 0, true, Some(RadioStation::FranceInter), [true; 7])
+    }
+
+    /// Heure du lundi 19 octobre 2026.
+    pub fn at(hour: u32, minute: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 10, 19, hour, minute, 0).unwrap()
+    }
+
+    /// `dbus-daemon` privé sur lequel les tests publient de faux services ; il est arrêté à la fin du test.
+    pub struct PrivateBus {
+        daemon: Child,
+        /// Adresse du bus, pour `ConnectionBuilder::address`.
+        pub address: String,
+    }
+
+    impl PrivateBus {
+        /// Lance un `dbus-daemon` privé.
+        ///
+        /// Le test échoue si `dbus-daemon` n'est pas installé, plutôt que de réussir sans rien vérifier.
+        ///
+        /// # Returns
+        ///
+        /// Le bus démarré.
+        pub fn start() -> Self {
+            let mut daemon = Command::new("dbus-daemon")
+                .args(["--session", "--nofork", "--print-address"])
+                .stdout(Stdio::piped())
+                .spawn()
+                .expect("dbus-daemon is required by the D-Bus tests");
+            let mut address = String::new();
+            BufReader::new(daemon.stdout.as_mut().unwrap()).read_line(&mut address).unwrap();
+            PrivateBus {
+                daemon,
+                address: address.trim().to_string(),
+            }
+        }
+    }
+
+    impl Drop for PrivateBus {
+        fn drop(&mut self) {
+            let _ = self.daemon.kill();
+            let _ = self.daemon.wait();
+        }
+    }
+}
@@ -0,0 +1,207 @@
+pub mod tray {
+    use chrono::Local;
+    use ksni::menu::{MenuItem, StandardItem, SubMenu};
+    use ksni::{ToolTip, Tray, TrayService};
+    use tokio::sync::broadcast::{self, error::RecvError};
+    use tokio::time::{interval, Duration};
+    use tracing::{error, warn};
+    use crate::configmod::config::TrayConfig;
+    use crate::controlmod::control::{self, Command, ControlRequest, Event};
+    use crate::modelmod::model::{self, AlarmClock, RadioStation};
+
+    /// Intervalle de mise à jour de la prochaine alarme affichée, en secondes.
+    const REFRESH_SECS: u64 = 60;
+
+    /// Action choisie dans le menu de l'icône, exécutée dans la boucle GTK.
+    pub enum TrayAction {
+        /// Commande transmise à la vue, comme celles de la socket de contrôle.
+        Command(Command),
+        ShowWindow,
+        Quit,
+    }
+
+    /// Icône de la zone de notification (StatusNotifierItem).
+    struct AlarmTray {
+        actions: async_channel::Sender<TrayAction>,
+        favorites: Vec<RadioStation>,
+        next_alarm: Option<String>,
+        playing: bool,
+        any_active: bool,
+    }
+
+    impl AlarmTray {
+        /// Transmet une action à la boucle GTK.
+        fn send(&self, action: TrayAction) {
+            if self.actions.try_send(action).is_err() {
+                warn!("Tray action dropped, application not running");
+            }
+        }
+
+        /// Met à jour l'état affiché à partir des alarmes.
+        fn set_alarms(&mut self, alarms: &[AlarmClock]) {
+            self.any_active = alarms.iter().any(|alarm: &AlarmClock| alarm.active);
+            self.next_alarm = model::next_alarm(alarms, Local::now()).map(|(alarm, at)| {
+                if alarm.name.is_empty() {
+                    at.format("%a %H:%M").to_string()
+                } else {
+                    format!("{} {}", at.format("%a %H:%M"), alarm.name)
+                }
+            });
+        }
+    }
+
+    impl Tray for AlarmTray {
+        fn id(&self) -> String {
+            "alarm_clock".to_string()
+        }
+
+        fn title(&self) -> String {
+            "Alarm Clock".to_string()
+        }
+
+        fn icon_name(&self) -> String {
+            "alarm-symbolic".to_string()
+        }
+
+        fn tool_tip(&self) -> ToolTip {
+            ToolTip {
+                title: "Alarm Clock".to_string(),
+                description: self.next_alarm.clone().unwrap_or_else(|| "Aucune alarme programmée".to_string()),
+                ..Default::default()
+            }
+        }
+
+        /// Clic sur l'icône : présente la fenêtre.
+        fn activate(&mut self, _x: i32, _y: i32) {
+            self.send(TrayAction::ShowWindow);
+        }
+
+        fn menu(&self) -> Vec<MenuItem<Self>> {
+            let next_alarm = match &self.next_alarm {
+                Some(next_alarm) => format!("Prochaine alarme : {}", next_alarm),
+                None => "Aucune alarme programmée".to_string(),
+            };
+            let mut stations: Vec<MenuItem<Self>> = self.favorites.iter()
+                .map(|station: &RadioStation| {
+                    let name = station.label().to_string();
+                    StandardItem {
+                        label: station.label().to_string(),
+                        activate: Box::new(move |tray: &mut Self| tray.send(TrayAction::Command(Command::PlayRadio { station: name.clone() }))),
+                        ..Default::default()
+                    }
+                    .into()
+                })
+                .collect();
+            stations.push(MenuItem::Separator);
+            stations.push(StandardItem {
+                label: "Arrêter la lecture".to_string(),
+                enabled: self.playing,
+                activate: Box::new(|tray: &mut Self| tray.send(TrayAction::Command(Command::Stop))),
+                ..Default::default()
+            }
+            .into());
+
+            let (toggle_label, active) = if self.any_active {
+                ("Désactiver toutes les alarmes", false)
+            } else {
+                ("Activer toutes les alarmes", true)
+            };
+
+            vec![
+                StandardItem {
+                    label: next_alarm,
+                    enabled: false,
+                    ..Default::default()
+                }
+                .into(),
+                MenuItem::Separator,
+                SubMenu {
+                    label: "Radios favorites".to_string(),
+                    submenu: stations,
+                    ..Default::default()
+                }
+                .into(),
+                StandardItem {
+                    label: toggle_label.to_string(),
+                    activate: Box::new(move |tray: &mut Self| tray.send(TrayAction::Command(Command::SetAllActive { active }))),
+                    ..Default::default()
+                }
+                .into(),
+                MenuItem::Separator,
+                StandardItem {
+                    label: "Afficher la fenêtre".to_string(),
+                    activate: Box::new(|tray: &mut Self| tray.send(TrayAction::ShowWindow)),
+                    ..Default::default()
+                }
+                .into(),
+                StandardItem {
+                    label: "Quitter".to_string(),
+                    icon_name: "application-exit".to_string(),
+                    activate: Box::new(|tray: &mut Self| tray.send(TrayAction::Quit)),
+                    ..Default::default()
+                }
+                .into(),
+            ]
+        }
+    }
+
+    /// Affiche l'icône de la zone de notification si la configuration l'active.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - Configuration de l'icône (stations favorites).
+    /// * `requests` - Canal des commandes exécutées par l'application, pour lire les alarmes.
+    /// * `events` - Diffuseur des événements de l'application.
+    ///
+    /// # Returns
+    ///
+    /// Le récepteur des actions du menu, à exécuter dans la boucle GTK, ou `None` si l'icône est désactivée.
+    pub fn spawn(config: &TrayConfig, requests: async_channel::Sender<ControlRequest>, events: &broadcast::Sender<Event>) -> Option<async_channel::Receiver<TrayAction>> {
+        if !config.enabled {
+            return None;
+        }
+        let (actions, receiver) = async_channel::unbounded();
+        let favorites: Vec<RadioStation> = config.favorites.iter()
+            .filter_map(|name: &String| {
+                let station = RadioStation::from_name(name);
+                if station.is_none() {
+                    warn!(radio = %name, "Unknown favorite radio");
+                }
+                station
+            })
+            .collect();
+        let service = TrayService::new(AlarmTray {
+            actions,
+            favorites,
+            next_alarm: None,
+            playing: false,
+            any_active: false,
+        });
+        let handle = service.handle();
+        service.spawn();
+
+        let mut events = events.subscribe();
+        tokio::spawn(async move {
+            let mut refresh = interval(Duration::from_secs(REFRESH_SECS));
+            loop {
+                tokio::select! {
+                    event = events.recv() => match event {
+                        Ok(Event::PlayerState { playing, .. }) => handle.update(|tray: &mut AlarmTray| tray.playing = playing),
+                        Ok(Event::AlarmFired { .. }) => handle.update(|tray: &mut AlarmTray| tray.playing = true),
+                        Ok(Event::AlarmsChanged) => refresh.reset_immediately(),
+                        Ok(_) | Err(RecvError::Lagged(_)) => {}
+                        Err(RecvError::Closed) => break,
+                    },
+                    _ = refresh.tick() => match control::dispatch(&requests, Command::ListAlarms).await {
+                        Ok(alarms) => {
+                            let alarms: Vec<AlarmClock> = serde_json::from_value(alarms).unwrap_or_default();
+                            handle.update(|tray: &mut AlarmTray| tray.set_alarms(&alarms));
+                        }
+                        Err(error) => error!(%error, "Tray failed to list alarms"),
+                    },
+                }
+            }
+        });
+        Some(receiver)
+    }
+}
@@ -1,151 +1,155 @@
 pub mod view {
     use gtk::prelude::*;
     use gtk::{CssProvider, StyleContext, ApplicationWindow, Box, Button, Label, Orientation, glib, CheckButton};
+    use std::cell::RefCell;
+    use std::rc::Rc;
     use std::sync::{Arc, Mutex};
+    use chrono::Timelike;
+    use chrono_tz::Tz;
     use async_channel::{unbounded, Receiver, Sender};
-    use glib::{timeout_add_seconds, MainContext, ControlFlow};
-    use std::fs::{File, remove_file};
-    use std::io::{self, Read, Write};
-    use serde_json;
+    use glib::{timeout_add, MainContext, ControlFlow};
+    use std::fs::{self, remove_file};
     use std::{thread, time};
-    use chrono::prelude::*;
-    use crate::modelmod::model::{AlarmClock, Horaire, Radio, RadioStation};
-    use crate::musicmod::music::{WavPlayer, RadioPlayer, Music};
+    use tokio::sync::broadcast;
+    use tracing::{debug, error, info, warn};
+    use crate::clockmod::clock::Clock;
+    use crate::controlmod::control::{self, AlarmInfo, Command, ControlRequest, Event, Reply};
+    use crate::errormod::error::{Error, Result};
+    use crate::historymod::history::{History, Statistics};
+    use crate::hookmod::hook::{self, HookKind};
+    use crate::modelmod::model::{self, AlarmClock, Horaire, RadioStation, DAY_NAMES};
+    use crate::configmod::config::{AudioConfig, TimersConfig, WorldClockConfig};
+    use crate::musicmod::music::{self, Player};
+    use crate::schedulermod::scheduler::Scheduler;
+    use crate::statemod::state::{Change, PlayerStatus, Store};
+    use crate::timermod::timer::{self, Timer, TimerState};
     use crate::widgetmod::ihm::Widgets;
 
     /// Structure représentant la vue de l'application.
+    ///
+    /// Les alarmes, les minuteurs, l'état du lecteur et la radio sélectionnée sont lus dans le `Store` :
+    /// les copies de la vue passées aux gestionnaires de signaux partagent le même état.
     #[derive(Clone)]
     pub struct View {
         widgets: Arc<Widgets>,
-        alarms: Vec<AlarmClock>,
-        current_radio: Arc<Mutex<Radio>>,
+        store: Store,
         horaire: Arc<Mutex<Horaire>>,
         sender: Sender<()>,
-        radio_player: Arc<Mutex<RadioPlayer>>,
-        wav_player: Arc<Mutex<WavPlayer>>,
-        player_status: bool,
+        radio_player: Arc<Mutex<Player>>,
+        wav_player: Arc<Mutex<Player>>,
+        scheduler: Arc<Mutex<Scheduler>>,
+        events: broadcast::Sender<Event>,
+        clock: Arc<dyn Clock>,
+        timer_sound: Option<String>,
+        /// Temps restant affiché pour chaque minuteur, mis à jour à chaque seconde.
+        timer_labels: Rc<RefCell<Vec<(usize, Label)>>>,
     }
 
     impl View {
         /// Crée une nouvelle instance de `View`.
         ///
+        /// # Parameters
+        ///
+        /// * `events` - Diffuseur des événements de l'application (socket de contrôle).
+        /// * `clock` - Horloge de l'application, lue pour l'affichage et la planification.
+        /// * `store` - État de l'application.
+        /// * `audio` - Configuration de la sortie audio.
+        /// * `world_clock` - Configuration de l'horloge mondiale.
+        /// * `timers` - Configuration des minuteurs.
+        ///
         /// # Returns
         ///
         /// Une nouvelle instance de `View`.
-        pub fn new() -> Self {
+        pub fn new(events: broadcast::Sender<Event>, clock: Arc<dyn Clock>, store: Store, audio: &AudioConfig, world_clock: &WorldClockConfig, timers: &TimersConfig) -> Self {
             let (sender, receiver) = unbounded();
-            let widgets = Widgets::new();
-            let alarms = vec![];
-            let current_radio = Arc::new(Mutex::new(Radio::new()));
-            let horaire = Arc::new(Mutex::new(Horaire::new()));
-            let radio_player = Arc::new(Mutex::new(RadioPlayer::new()));
-            let wav_player = Arc::new(Mutex::new(WavPlayer::new()));
-            let player_status = false;
-
-            let mut view = Self {
+            let cities = if world_clock.enabled { world_clock.cities.clone() } else { Vec::new() };
+            let widgets = Widgets::new(&cities, &timers.presets);
+            let horaire = Arc::new(Mutex::new(Horaire::new(clock.now())));
+            let stream_events = events.clone();
+            let (radio_player, wav_player) = music::players(audio, clock.clone(), move |url: String, error: String| {
+                let _ = stream_events.send(Event::stream_failed(url, error));
+            });
+            let radio_player = Arc::new(Mutex::new(radio_player));
+            let wav_player = Arc::new(Mutex::new(wav_player));
+            // En simulation, l'historique reste en mémoire pour ne pas fausser les statistiques.
+            let history = if clock.is_simulated() {
+                History::default()
+            } else {
+                // Un historique illisible n'est pas écrasé : les événements restent en mémoire.
+                History::load().unwrap_or_else(|error| {
+                    error!(?error, "Failed to load history, keeping it in memory");
+                    History::default()
+                })
+            };
+
+            let mut scheduler = Scheduler::new(history, clock.clone());
+            scheduler.set_alarms(store.alarms());
+
+            let view = Self {
                 widgets: Arc::new(widgets),
-                alarms,
-                current_radio,
+                store,
                 horaire,
                 sender,
                 radio_player,
                 wav_player,
-                player_status,
+                scheduler: Arc::new(Mutex::new(scheduler)),
+                events,
+                clock,
+                timer_sound: timers.sound.clone(),
+                timer_labels: Rc::new(RefCell::new(Vec::new())),
             };
             view.connect_receiver(receiver);
+            view.connect_store();
             view
         }
 
-        /// Met à jour l'identifiant des alarmes.
-        fn update_alarm_id(&mut self) {
-            let mut count = 0;
-            for alarm in self.alarms.iter_mut() {
-                alarm.a_id = count;
-                count += 1;
-            }
-        }
-
-        /// Ajoute des alarmes en les chargeant depuis le fichier de sauvegarde.
-        fn add_alarms(&mut self) {
-            let _res = match self.load_alarms() {
-                Ok(_res) => println!("[INFO] File loaded"),
-                Err(error) => println!("[ERROR] Failed to load alarms {error:?}"),
-            };
+        /// Ajoute l'alarme décrite par le formulaire.
+        fn add_alarms(&self) {
             let mut days = [false; 7];
             for (i, day_checkbox) in self.widgets.days_checkbuttons.iter().enumerate() {
                 days[i] = day_checkbox.is_active();
             }
             let name_alarm = self.widgets.i_name_ac.text().to_string();
             let url_song = self.widgets.i_song_link.text().to_string();
-            let tmp_alarm: AlarmClock;
-            if url_song.is_empty() && self.current_radio.lock().unwrap().selected_radio.is_none() {
-                println!("[ERROR] No song URL & No radio selected");
+            let time_zone = self.widgets.c_time_zone.active_id().and_then(|zone| zone.parse::<Tz>().ok());
+            let selected_radio = self.store.selected_radio();
+            if url_song.is_empty() && selected_radio.is_none() {
+                warn!("No song URL & No radio selected");
             } else if !url_song.is_empty() {
-                tmp_alarm = AlarmClock::new(
-                    self.alarms.len(),
+                let mut tmp_alarm = AlarmClock::new(
+                    model::next_alarm_id(&self.store.alarms()),
                     name_alarm,
                     self.widgets.s_heur_box.value() as u8,
                     self.widgets.s_min_box.value() as u8,
                     self.widgets.s_sec_box.value() as u8,
-                    url_song,
                     false,
                     None,
                     days,
                 );
-                self.alarms.push(tmp_alarm);
+                tmp_alarm.time_zone = time_zone;
+                // L'alarme est ajoutée tout de suite ; sa chanson est téléchargée sans bloquer l'interface.
+                self.store.update_alarms(|alarms: &mut Vec<AlarmClock>| alarms.push(tmp_alarm.clone()));
+                control::download_song(self.store.clone(), self.events.clone(), tmp_alarm, url_song);
             } else {
-                tmp_alarm = AlarmClock::new(
-                    self.alarms.len(),
+                let mut tmp_alarm = AlarmClock::new(
+                    model::next_alarm_id(&self.store.alarms()),
                     name_alarm,
                     self.widgets.s_heur_box.value() as u8,
                     self.widgets.s_min_box.value() as u8,
                     self.widgets.s_sec_box.value() as u8,
-                    "".to_string(),
                     true,
-                    self.current_radio.lock().unwrap().selected_radio.clone(),
+                    selected_radio,
                     days.clone(),
                 );
-                self.alarms.push(tmp_alarm);
+                tmp_alarm.time_zone = time_zone;
+                self.store.update_alarms(|alarms: &mut Vec<AlarmClock>| alarms.push(tmp_alarm));
             }
         }
 
-        /// Sauvegarde les alarmes dans un fichier.
-        ///
-        /// # Returns
-        ///
-        /// `io::Result<()>` - Résultat de l'opération de sauvegarde.
-        fn save_alarms(&mut self) -> io::Result<()> {
-            let alarms = &self.alarms;
-            let serialized = serde_json::to_string(&*alarms)?;
-            let mut file = File::create("ser/alarms.json")?;
-            file.write_all(serialized.as_bytes())?;
-            Ok(())
-        }
-
-        /// Charge les alarmes depuis un fichier.
-        ///
-        /// # Returns
-        ///
-        /// `io::Result<()>` - Résultat de l'opération de chargement.
-        pub fn load_alarms(&mut self) -> io::Result<()> {
-            let mut file = File::open("ser/alarms.json").unwrap_or_else(|_| File::create("ser/alarms.json").unwrap());
-            let mut contents = String::new();
-            file.read_to_string(&mut contents)?;
-            if !contents.is_empty() {
-                let alarms: Vec<AlarmClock> = serde_json::from_str(&contents)?;
-                self.alarms = alarms.to_vec();
-            } else {
-                self.alarms = Vec::new();
-                println!("[INFO] No alarms found");
-            }
-            self.update_alarm_id();
-            Ok(())
-        }
-
         /// Met à jour l'affichage des alarmes.
-        fn update_alarms_display(&mut self) {
+        fn update_alarms_display(&self) {
             self.widgets.alarms_container.foreach(|child: &gtk::Widget| self.widgets.alarms_container.remove(child));
-            for alarm in self.alarms.iter() {
+            for alarm in self.store.alarms().iter() {
                 let vbox_alarm = Box::new(Orientation::Vertical, 5);
                 vbox_alarm.set_widget_name("box-alarm");
                 let hbox_alarm = Box::new(Orientation::Horizontal, 5);
@@ -166,36 +170,36 @@ pub mod view {
                 hbox_alarm.pack_start(&Label::new(Some("Min")), false, false, 0);
                 hbox_alarm.pack_start(&sec_label, true, true, 0);
                 hbox_alarm.pack_start(&Label::new(Some("Sec")), false, false, 0);
+                if let Some(zone) = alarm.time_zone {
+                    hbox_alarm.pack_start(&Label::new(Some(zone.name())), false, false, 0);
+                }
                 hbox_alarm.pack_start(&link_label, true, true, 0);
                 hbox_alarm.pack_start(&alamrm_name, true, true, 0);
     
-                // Affichage des jours
-                let days = ["Lun", "Mar", "Mer", "Jeu", "Ven", "Sam", "Dim"];
-                for (i, &day) in days.iter().enumerate() {
-                    let day_label = Label::new(Some(day));
-                    let day_checkbox = CheckButton::new();
-                    day_checkbox.set_active(alarm.days[i]);
-                    day_checkbox.set_sensitive(false);
-                    hbox_days.pack_start(&day_label, true, true, 0);
-                    hbox_days.pack_start(&day_checkbox, true, true, 0);
+                // Affichage des jours, ou de la date d'une alarme unique
+                if let Some(date) = alarm.date {
+                    hbox_days.pack_start(&Label::new(Some(&date.format("Le %d/%m/%Y").to_string())), true, true, 0);
+                } else {
+                    for (i, &day) in DAY_NAMES.iter().enumerate() {
+                        let day_label = Label::new(Some(day));
+                        let day_checkbox = CheckButton::new();
+                        day_checkbox.set_active(alarm.days[i]);
+                        day_checkbox.set_sensitive(false);
+                        hbox_days.pack_start(&day_label, true, true, 0);
+                        hbox_days.pack_start(&day_checkbox, true, true, 0);
+                    }
                 }
 
                 let delete_button = Button::with_label("Supprimer");
                 let active_radio = CheckButton::with_label("Active");
                 active_radio.set_active(alarm.active);
                 let delete_alarm_id = alarm.a_id;
-                let view_rc = Arc::new(Mutex::new(self.clone()));
 
-                delete_button.connect_clicked(move |_| {
-                    let mut view = view_rc.lock().unwrap();
-                    view.delete_alarm(delete_alarm_id);
-                });
+                let view = self.clone();
+                delete_button.connect_clicked(move |_| view.delete_alarm(delete_alarm_id));
 
-                let view_rc = Arc::new(Mutex::new(self.clone()));
-                active_radio.connect_clicked(move |_| {
-                    let mut view = view_rc.lock().unwrap();
-                    view.alarm_status(delete_alarm_id);
-                });
+                let view = self.clone();
+                active_radio.connect_clicked(move |_| view.alarm_status(delete_alarm_id));
 
                 hbox_alarm.pack_start(&active_radio, false, false, 0);
                 hbox_alarm.pack_start(&delete_button, false, false, 0);
@@ -212,15 +216,14 @@ pub mod view {
         /// # Parameters
         ///
         /// * `alarm_id` - Identifiant de l'alarme à mettre à jour.
-        fn alarm_status(&mut self, alarm_id: usize) {
-            for alarm in self.alarms.iter_mut() {
-                if alarm.a_id == alarm_id {
-                    alarm.active = !alarm.active;
+        fn alarm_status(&self, alarm_id: usize) {
+            self.store.update_alarms(|alarms: &mut Vec<AlarmClock>| {
+                for alarm in alarms.iter_mut() {
+                    if alarm.a_id == alarm_id {
+                        alarm.active = !alarm.active;
+                    }
                 }
-            }
-
-            self.save_alarms().expect("Failed to save alarms");
-            self.update_alarms_display();
+            });
         }
 
         /// Supprime une chanson en fonction du chemin fourni.
@@ -228,7 +231,7 @@ pub mod view {
         /// # Parameters
         ///
         /// * `path` - Chemin de la chanson à supprimer.
-        fn delet_song(&mut self, path: String) {
+        fn delet_song(&self, path: String) {
             let _ = remove_file(path);
         }
 
@@ -237,14 +240,16 @@ pub mod view {
         /// # Parameters
         ///
         /// * `alarm_id` - Identifiant de l'alarme à supprimer.
-        fn delete_alarm(&mut self, alarm_id: usize) {
-            if let Some(index) = self.alarms.iter().position(|alarm: &AlarmClock| alarm.a_id == alarm_id) {
-                if !self.alarms[index].is_radio {
-                    self.delet_song(self.alarms[index].song_path.clone());
+        fn delete_alarm(&self, alarm_id: usize) {
+            let removed = self.store.update_alarms(|alarms: &mut Vec<AlarmClock>| {
+                alarms.iter()
+                    .position(|alarm: &AlarmClock| alarm.a_id == alarm_id)
+                    .map(|index: usize| alarms.remove(index))
+            });
+            if let Some(alarm) = removed {
+                if !alarm.is_radio {
+                    self.delet_song(alarm.song_path);
                 }
-                self.alarms.remove(index);
-                self.save_alarms().expect("Failed to save alarms");
-                self.update_alarms_display();
             }
         }
 
@@ -253,17 +258,23 @@ pub mod view {
         /// # Parameters
         ///
         /// * `window` - Fenêtre principale de l'application.
-        pub fn build_ui(&mut self, window: &ApplicationWindow) {
+        pub fn build_ui(&self, window: &ApplicationWindow) {
+            // Sans feuille de style, l'interface reste utilisable avec le thème par défaut.
             let provider = CssProvider::new();
-            provider.load_from_path("style/styleapp.css").expect("Failed to load CSS");
-
-            StyleContext::add_provider_for_screen(
-                &gtk::prelude::WidgetExt::screen(window).unwrap(),
-                &provider,
-                gtk::STYLE_PROVIDER_PRIORITY_APPLICATION,
-            );
+            match provider.load_from_path("style/styleapp.css") {
+                Ok(()) => {
+                    if let Some(screen) = gtk::prelude::WidgetExt::screen(window) {
+                        StyleContext::add_provider_for_screen(&screen, &provider, gtk::STYLE_PROVIDER_PRIORITY_APPLICATION);
+                    }
+                }
+                Err(error) => self.report(&Error::Config(format!("style/styleapp.css : {}", error))),
+            }
         
-            window.set_title("Alarm Clock");
+            if self.clock.is_simulated() {
+                window.set_title(&format!("Alarm Clock (simulation ×{})", self.clock.speed()));
+            } else {
+                window.set_title("Alarm Clock");
+            }
             window.set_default_size(600, 800);
 
             let vbox = Box::new(Orientation::Vertical, 10);
@@ -279,9 +290,15 @@ pub mod view {
             hbox1.pack_start(&self.widgets.p_lcd_sec, true, true, 0);
             hbox1.pack_start(&Label::new(Some("Sec")), false, false, 0);
 
+            let hbox_system = Box::new(Orientation::Horizontal, 5);
+            hbox_system.pack_start(&self.widgets.p_wake_label, true, true, 0);
+            hbox_system.pack_start(&self.widgets.p_inhibit_label, true, true, 0);
+            self.widgets.p_error_label.set_widget_name("label-error");
+
             let hbox2 = Box::new(Orientation::Horizontal, 5);
             hbox2.pack_start(&self.widgets.p_button_marche, true, true, 0);
             hbox2.pack_start(&self.widgets.p_button_add_alarm_clock, true, true, 0);
+            hbox2.pack_start(&self.widgets.p_button_snooze, true, true, 0);
             hbox2.pack_start(&self.widgets.p_button_arret, true, true, 0);
 
             let hbox_rad_b = Box::new(Orientation::Horizontal, 5);
@@ -300,6 +317,7 @@ pub mod view {
             hbox_reveil.pack_start(&Label::new(Some("Min")), false, false, 0);
             hbox_reveil.pack_start(&self.widgets.s_sec_box, true, true, 0);
             hbox_reveil.pack_start(&Label::new(Some("Sec")), false, false, 0);
+            hbox_reveil.pack_start(&self.widgets.c_time_zone, false, false, 0);
             self.widgets.i_name_ac.set_placeholder_text("Nom de l'alarme".into());
             self.widgets.i_song_link.set_placeholder_text("URL de la musique".into());
             hbox_reveil.pack_start(&self.widgets.i_name_ac, true, true, 0);
@@ -316,121 +334,279 @@ pub mod view {
             self.widgets.g_alarm_clock.add(&vbox_reveil);
 
             self.widgets.g_alarm_clock_tab.add(&self.widgets.alarms_container);
+
+            let vbox_stats = Box::new(Orientation::Vertical, 5);
+            let hbox_stats = Box::new(Orientation::Horizontal, 5);
+            hbox_stats.pack_start(&self.widgets.p_stats_refresh, true, true, 0);
+            hbox_stats.pack_start(&self.widgets.p_export_csv, true, true, 0);
+            hbox_stats.pack_start(&self.widgets.p_export_json, true, true, 0);
+            vbox_stats.pack_start(&self.widgets.p_stats_label, false, false, 5);
+            vbox_stats.add(&hbox_stats);
+            self.widgets.g_stats.add(&vbox_stats);
+
+            let vbox_world_clock = Box::new(Orientation::Vertical, 5);
+            for row in self.widgets.world_clock_rows.iter() {
+                let hbox_city = Box::new(Orientation::Horizontal, 5);
+                let name_label = Label::new(Some(&row.city.name));
+                name_label.set_xalign(0.0);
+                hbox_city.pack_start(&row.p_daylight, false, false, 0);
+                hbox_city.pack_start(&name_label, true, true, 0);
+                hbox_city.pack_start(&row.p_time, true, true, 0);
+                hbox_city.pack_start(&row.p_day_offset, true, true, 0);
+                hbox_city.pack_start(&row.p_add_alarm, false, false, 0);
+                vbox_world_clock.add(&hbox_city);
+            }
+            self.widgets.g_world_clock.add(&vbox_world_clock);
+
+            let vbox_timers = Box::new(Orientation::Vertical, 5);
+            let hbox_presets = Box::new(Orientation::Horizontal, 5);
+            for preset in self.widgets.timer_presets.iter() {
+                hbox_presets.pack_start(&preset.p_start, true, true, 0);
+            }
+            let hbox_timer = Box::new(Orientation::Horizontal, 5);
+            self.widgets.i_timer_name.set_placeholder_text("Nom du minuteur".into());
+            hbox_timer.pack_start(&self.widgets.i_timer_name, true, true, 0);
+            hbox_timer.pack_start(&self.widgets.s_timer_min, false, false, 0);
+            hbox_timer.pack_start(&Label::new(Some("Min")), false, false, 0);
+            hbox_timer.pack_start(&self.widgets.s_timer_sec, false, false, 0);
+            hbox_timer.pack_start(&Label::new(Some("Sec")), false, false, 0);
+            hbox_timer.pack_start(&self.widgets.p_timer_start, false, false, 0);
+            vbox_timers.add(&hbox_presets);
+            vbox_timers.add(&hbox_timer);
+            vbox_timers.add(&self.widgets.timers_container);
+            self.widgets.g_timers.add(&vbox_timers);
+
+            let hbox_lists = Box::new(Orientation::Horizontal, 10);
+            hbox_lists.pack_start(&self.widgets.g_alarm_clock_tab, true, true, 0);
+            hbox_lists.pack_start(&self.widgets.g_timers, true, true, 0);
+
             vbox.pack_start(&hbox1, false, false, 10);
+            if !self.widgets.world_clock_rows.is_empty() {
+                vbox.pack_start(&self.widgets.g_world_clock, false, false, 0);
+            }
+            vbox.pack_start(&hbox_system, false, false, 0);
+            vbox.pack_start(&self.widgets.p_error_label, false, false, 0);
             vbox.pack_start(&hbox2, false, false, 10);
             vbox.pack_start(&self.widgets.g_alarm_clock, false, false, 10);
             vbox.pack_start(&hbox_rad_b, false, false, 20);
-            vbox.pack_start(&self.widgets.g_alarm_clock_tab, false, true, 10); 
+            vbox.pack_start(&hbox_lists, false, true, 10);
+            vbox.pack_start(&self.widgets.g_stats, false, false, 10);
 
             window.add(&vbox);
             
             // Met à jour les alarmes et le temps
             self.update_alarms_display();
+            self.update_timers_display();
+            self.update_world_clock();
+            self.on_stats_refresh_clicked();
             unsafe { self.update_time_labels() };
         }
 
         /// Connecte les signaux aux boutons et autres widgets.
-        pub fn connect_signals(&mut self) {
-            let view_rc = Arc::new(Mutex::new(self.clone()));
-
+        pub fn connect_signals(&self) {
             // Bouton Marche
-            let view_clone = view_rc.clone();
-            self.widgets.p_button_marche.connect_clicked(move |_| {
-                let mut view = view_clone.lock().unwrap();
-                view.on_marche_clicked();
-            });
+            let view = self.clone();
+            self.widgets.p_button_marche.connect_clicked(move |_| view.on_marche_clicked());
 
             // Bouton Arrêt
-            let view_clone = view_rc.clone();
-            self.widgets.p_button_arret.connect_clicked(move |_| {
-                let mut view = view_clone.lock().unwrap();
-                view.on_arret_clicked();
-            });
+            let view = self.clone();
+            self.widgets.p_button_arret.connect_clicked(move |_| view.on_arret_clicked());
+
+            // Bouton Répéter
+            let view = self.clone();
+            self.widgets.p_button_snooze.connect_clicked(move |_| view.on_snooze_clicked());
+
+            // Boutons Statistiques
+            let view = self.clone();
+            self.widgets.p_stats_refresh.connect_clicked(move |_| view.on_stats_refresh_clicked());
+
+            let view = self.clone();
+            self.widgets.p_export_csv.connect_clicked(move |_| view.on_export_clicked(false));
+
+            let view = self.clone();
+            self.widgets.p_export_json.connect_clicked(move |_| view.on_export_clicked(true));
 
             // Bouton Ajouter un réveil
-            let view_clone = view_rc.clone();
-            self.widgets.p_button_add_alarm_clock.connect_clicked(move |_| {
-                let view = view_clone.lock().unwrap();
-                view.on_new_alarm_clicked();
-            });
+            let view = self.clone();
+            self.widgets.p_button_add_alarm_clock.connect_clicked(move |_| view.on_new_alarm_clicked());
 
             // Bouton Sauvegarder
-            let view_clone = view_rc.clone();
-            self.widgets.p_save.connect_clicked(move |_| {
-                let mut view = view_clone.lock().unwrap();
-                view.on_save_clicked();
-            });
+            let view = self.clone();
+            self.widgets.p_save.connect_clicked(move |_| view.on_save_clicked());
 
             // Bouton Annuler
-            let view_clone = view_rc.clone();
-            self.widgets.p_cancel.connect_clicked(move |_| {
-                let view = view_clone.lock().unwrap();
-                view.on_cancel_clicked();
-            });
+            let view = self.clone();
+            self.widgets.p_cancel.connect_clicked(move |_| view.on_cancel_clicked());
+
+            // Boutons de l'horloge mondiale
+            for (index, row) in self.widgets.world_clock_rows.iter().enumerate() {
+                let view = self.clone();
+                row.p_add_alarm.connect_clicked(move |_| view.on_world_clock_alarm_clicked(index));
+            }
+
+            // Boutons des minuteurs
+            for preset in self.widgets.timer_presets.iter() {
+                let view = self.clone();
+                let (name, seconds) = (preset.preset.name.clone(), preset.preset.seconds as i64);
+                preset.p_start.connect_clicked(move |_| view.start_timer(name.clone(), seconds));
+            }
+
+            let view = self.clone();
+            self.widgets.p_timer_start.connect_clicked(move |_| view.on_timer_start_clicked());
 
             // Boutons Radio
-            let view_clone = view_rc.clone();
+            let view = self.clone();
             self.widgets.p_rad_b1.connect_toggled(move |radio| {
                 if radio.is_active() {
-                    let mut view = view_clone.lock().unwrap();
                     view.on_radio_clicked(1);
                 }
             });
 
-            let view_clone = view_rc.clone();
+            let view = self.clone();
             self.widgets.p_rad_b2.connect_toggled(move |radio| {
                 if radio.is_active() {
-                    let mut view = view_clone.lock().unwrap();
                     view.on_radio_clicked(2);
                 }
             });
 
-            let view_clone = view_rc.clone();
+            let view = self.clone();
             self.widgets.p_rad_b3.connect_toggled(move |radio| {
                 if radio.is_active() {
-                    let mut view = view_clone.lock().unwrap();
                     view.on_radio_clicked(3);
                 }
             });
 
-            let view_clone = view_rc.clone();
+            let view = self.clone();
             self.widgets.p_rad_b4.connect_toggled(move |radio| {
                 if radio.is_active() {
-                    let mut view = view_clone.lock().unwrap();
                     view.on_radio_clicked(4);
                 }
             });
 
-            let view_clone = view_rc.clone();
+            let view = self.clone();
             self.widgets.p_rad_b5.connect_toggled(move |radio| {
                 if radio.is_active() {
-                    let mut view = view_clone.lock().unwrap();
                     view.on_radio_clicked(5);
                 }
             });
         }
 
         /// Vérifie les alarmes et déclenche celles qui sont actives à l'heure actuelle.
-        pub fn check_alarms(&mut self) {
-            let _res = match self.load_alarms() {
-                Ok(_res) => {},
-                Err(error) => println!("[ERROR] Failed to load alarms {error:?}"),
-            };
+        pub fn check_alarms(&self) {
+            // La file de commandes appartient à l'instance réelle : une simulation ne la consomme pas.
+            if !self.clock.is_simulated() {
+                for command in control::take_pending() {
+                    if let Err(error) = self.execute(command) {
+                        error!(%error, "Command failed");
+                    }
+                }
+            }
 
-            let current_time = self.horaire.lock().unwrap().clone();
-            let day_of_week = Local::now().weekday().num_days_from_monday() as usize; // 0 pour Lundi, 6 pour Dimanche
-            for alarm in self.alarms.iter() {
-                if alarm.active && alarm.to_compare(&current_time, day_of_week) {
-                    if alarm.is_radio {
-                        self.current_radio.lock().unwrap().selected_radio = alarm.a_radio.clone();
-                        self.start_player(true, "".to_string());
-                        break;
-                    } else {
-                        self.start_player(false, alarm.song_path.clone());
-                        break;
+            let tick = self.scheduler.lock().unwrap().tick();
+            let one_shot_passed = tick.missed.iter().chain(tick.fired.iter()).any(|alarm: &AlarmClock| alarm.date.is_some());
+            for alarm in tick.missed.iter() {
+                self.emit(Event::AlarmMissed { alarm: AlarmInfo::new(alarm) });
+            }
+            for alarm in tick.pre_alarms.iter() {
+                hook::run(alarm, HookKind::PreAlarm);
+            }
+            if let Some(alarm) = tick.fired {
+                self.trigger_alarm(alarm);
+            }
+            if one_shot_passed {
+                let now = self.clock.now();
+                self.store.update_alarms(|alarms: &mut Vec<AlarmClock>| model::disable_expired(alarms, now));
+            }
+            self.check_timers();
+        }
+
+        /// Exécute une commande reçue d'un autre processus (ligne de commande, socket de contrôle).
+        ///
+        /// # Parameters
+        ///
+        /// * `command` - Commande à exécuter.
+        ///
+        /// # Returns
+        ///
+        /// La réponse à renvoyer au client.
+        pub fn execute(&self, command: Command) -> Reply {
+            info!(?command, "Command received");
+            let mut alarms = self.store.alarms();
+            if let Some(reply) = control::edit_alarms(&mut alarms, &command) {
+                if reply.is_ok() && !matches!(command, Command::ListAlarms) {
+                    let added = alarms.last().cloned();
+                    self.store.update_alarms(|current: &mut Vec<AlarmClock>| *current = alarms);
+                    // La chanson est téléchargée après l'ajout, sans bloquer l'interface.
+                    if let (Command::AddAlarm(new_alarm), Some(alarm)) = (&command, added) {
+                        if let Some(url) = &new_alarm.url {
+                            control::download_song(self.store.clone(), self.events.clone(), alarm, url.clone());
+                        }
                     }
                 }
+                return reply;
             }
+
+            match command {
+                Command::PlayRadio { station } => {
+                    let station = RadioStation::from_name(&station).ok_or_else(|| format!("Unknown radio {}", station))?;
+                    self.store.select_radio(Some(station));
+                    if self.store.player().playing {
+                        self.stop_player();
+                    }
+                    self.start_player(true, "".to_string()).map_err(|error: Error| {
+                        self.report(&error);
+                        error.to_string()
+                    })?;
+                }
+                Command::Snooze => self.on_snooze_clicked(),
+                Command::Stop | Command::Dismiss => self.on_arret_clicked(),
+                // Déjà exécutées par `control::edit_alarms`.
+                Command::ListAlarms | Command::AddAlarm(_) | Command::RemoveAlarm { .. } | Command::ToggleAlarm { .. } | Command::SetAllActive { .. } => {}
+            }
+            Ok(serde_json::Value::Null)
+        }
+
+        /// Diffuse un événement aux clients abonnés.
+        ///
+        /// # Parameters
+        ///
+        /// * `event` - Événement à diffuser.
+        fn emit(&self, event: Event) {
+            // Aucun abonné n'est pas une erreur.
+            let _ = self.events.send(event);
+        }
+
+        /// Déclenche une alarme et l'enregistre dans l'historique.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarm` - Alarme à déclencher.
+        fn trigger_alarm(&self, alarm: AlarmClock) {
+            if !alarm.is_playable() {
+                self.report(&Error::Playback(format!("rien à jouer pour l'alarme {}", alarm.a_id)));
+                self.scheduler.lock().unwrap().failed(&alarm);
+                self.emit(Event::PlaybackFailed { alarm: AlarmInfo::new(&alarm) });
+                return;
+            }
+
+            info!(alarm_id = alarm.a_id, name = %alarm.name, source = %alarm.source_label(), "Alarm ringing");
+            let started = if alarm.is_radio {
+                self.store.select_radio(alarm.a_radio.clone());
+                self.start_player(true, "".to_string())
+            } else {
+                self.start_player(false, alarm.song_path.clone())
+            };
+            // Une alarme qui ne peut pas être lue est enregistrée comme échouée ; les suivantes sonneront.
+            if let Err(error) = started {
+                warn!(alarm_id = alarm.a_id, "Alarm could not be played");
+                self.report(&error);
+                self.scheduler.lock().unwrap().failed(&alarm);
+                self.emit(Event::PlaybackFailed { alarm: AlarmInfo::new(&alarm) });
+                return;
+            }
+            self.emit(Event::AlarmFired { alarm: AlarmInfo::new(&alarm) });
+            hook::run(&alarm, HookKind::Fire);
+            self.scheduler.lock().unwrap().fired(alarm);
         }
 
         /// Démarre le lecteur de musique ou de radio.
@@ -439,44 +615,101 @@ pub mod view {
         ///
         /// * `radio` - Indique s'il s'agit d'une radio.
         /// * `file_path` - Chemin du fichier à lire.
-        fn start_player(&mut self, radio: bool, file_path: String) {
-            self.player_status = true;
-            let current_radio = self.current_radio.clone();
-            let radio_player = self.radio_player.clone();
-            let wav_player = self.wav_player.clone();
-            gtk::glib::MainContext::default().spawn_local(async move {
-                if radio {
-                    if let Some(url) = current_radio.lock().unwrap().get_url() {
-                        radio_player.lock().unwrap().play(url.to_string());
-                    } else {
-                        println!("No radio selected");
-                    }
-                } else {
-                    wav_player.lock().unwrap().play(file_path);
-                }
-            });
+        ///
+        /// # Returns
+        ///
+        /// `Result<()>` - `Error::Playback` si la lecture n'a pas pu démarrer ; le lecteur reste alors arrêté.
+        fn start_player(&self, radio: bool, file_path: String) -> Result<()> {
+            let (source, started) = if radio {
+                let station = self.store.selected_radio()
+                    .ok_or_else(|| Error::Playback("aucune radio sélectionnée".to_string()))?;
+                (station.label().to_string(), self.radio_player.lock().unwrap().play(station.url().to_string()))
+            } else {
+                (file_path.clone(), self.wav_player.lock().unwrap().play(file_path))
+            };
+            started?;
+            self.store.set_player(PlayerStatus { playing: true, source: Some(source) });
+            Ok(())
+        }
+
+        /// Journalise une erreur et l'affiche dans la fenêtre ; elle est aussi diffusée aux clients.
+        ///
+        /// # Parameters
+        ///
+        /// * `error` - Erreur à signaler.
+        pub fn report(&self, error: &Error) {
+            error!(%error, "Error reported");
+            self.widgets.p_error_label.set_text(&error.to_string());
+            self.emit(Event::error(error));
         }
 
         /// Arrête le lecteur de musique ou de radio.
-        pub fn stop_player(&mut self) {
+        pub fn stop_player(&self) {
             self.radio_player.lock().unwrap().stop();
             self.wav_player.lock().unwrap().stop();
-            self.player_status = false;
+            self.store.set_player(PlayerStatus::default());
         }
 
         /// Gestionnaire pour le clic sur le bouton Marche.
-        pub fn on_marche_clicked(&mut self) {
-            if self.player_status {
-                println!("[INFO] Radio already running");
+        pub fn on_marche_clicked(&self) {
+            if self.store.player().playing {
+                info!("Radio already running");
                 self.stop_player();
             }
-            self.start_player(true, "".to_string());
+            if let Err(error) = self.start_player(true, "".to_string()) {
+                self.report(&error);
+            }
         }
 
         /// Gestionnaire pour le clic sur le bouton Arrêt.
-        pub fn on_arret_clicked(&mut self) {
+        pub fn on_arret_clicked(&self) {
             self.stop_player();
-            println!("[INFO] Stop Radio");
+            info!("Stop Radio");
+            if self.store.timers().iter().any(Timer::is_ringing) {
+                self.store.update_timers(|timers: &mut Vec<Timer>| timers.iter_mut().filter(|timer: &&mut Timer| timer.is_ringing()).for_each(|timer: &mut Timer| timer.reset()));
+            }
+            let dismissed = self.scheduler.lock().unwrap().dismiss();
+            if let Some(alarm) = dismissed {
+                self.emit(Event::AlarmDismissed { alarm: AlarmInfo::new(&alarm) });
+                hook::run(&alarm, HookKind::Dismiss);
+            }
+        }
+
+        /// Gestionnaire pour le clic sur le bouton Répéter : reporte l'alarme qui sonne.
+        pub fn on_snooze_clicked(&self) {
+            let snoozed = self.scheduler.lock().unwrap().snooze();
+            match snoozed {
+                Some((alarm, due)) => {
+                    self.stop_player();
+                    info!(alarm_id = alarm.a_id, until = %due.format("%H:%M:%S"), "Alarm snoozed");
+                    self.emit(Event::AlarmSnoozed { alarm: AlarmInfo::new(&alarm), until: due });
+                }
+                None => info!("No alarm ringing"),
+            }
+        }
+
+        /// Met à jour le résumé des statistiques de réveil.
+        fn on_stats_refresh_clicked(&self) {
+            let stats = Statistics::from_history(self.scheduler.lock().unwrap().history());
+            self.widgets.p_stats_label.set_text(&stats.summary());
+        }
+
+        /// Exporte les statistiques de réveil dans `ser/`.
+        ///
+        /// # Parameters
+        ///
+        /// * `json` - `true` pour un export JSON, `false` pour un export CSV.
+        fn on_export_clicked(&self, json: bool) {
+            let stats = Statistics::from_history(self.scheduler.lock().unwrap().history());
+            let (path, contents) = if json {
+                ("ser/stats.json", stats.to_json().unwrap_or_default())
+            } else {
+                ("ser/stats.csv", stats.to_csv())
+            };
+            match fs::write(path, contents) {
+                Ok(_) => info!(path, "Statistics exported"),
+                Err(error) => error!(?error, "Failed to export statistics"),
+            }
         }
 
         /// Affiche le formulaire pour ajouter une nouvelle alarme.
@@ -485,14 +718,203 @@ pub mod view {
             self.widgets.s_heur_box.set_value(horaire.get_hour() as f64);
             self.widgets.s_min_box.set_value(horaire.get_min() as f64);
             self.widgets.s_sec_box.set_value(horaire.get_sec() as f64);
+            self.widgets.c_time_zone.set_active_id(Some(""));
+            self.widgets.g_alarm_clock.show_all();
+        }
+
+        /// Affiche le formulaire pour ajouter une alarme à l'heure d'une ville de l'horloge mondiale.
+        ///
+        /// # Parameters
+        ///
+        /// * `index` - Position de la ville dans l'horloge mondiale.
+        fn on_world_clock_alarm_clicked(&self, index: usize) {
+            let Some(row) = self.widgets.world_clock_rows.get(index) else {
+                return;
+            };
+            let time = row.city.time(self.clock.now()).time;
+            self.widgets.s_heur_box.set_value(time.hour() as f64);
+            self.widgets.s_min_box.set_value(time.minute() as f64);
+            self.widgets.s_sec_box.set_value(0.0);
+            self.widgets.c_time_zone.set_active_id(Some(row.city.zone.name()));
+            if self.widgets.i_name_ac.text().is_empty() {
+                self.widgets.i_name_ac.set_text(&row.city.name);
+            }
             self.widgets.g_alarm_clock.show_all();
         }
 
+        /// Met à jour l'heure, l'écart de jour et l'indicateur jour/nuit des villes de l'horloge mondiale.
+        fn update_world_clock(&self) {
+            let now = self.clock.now();
+            for row in self.widgets.world_clock_rows.iter() {
+                let city_time = row.city.time(now);
+                row.p_time.set_text(&city_time.time.format("%H:%M %Z").to_string());
+                row.p_day_offset.set_text(&city_time.day_offset_label());
+                row.p_daylight.set_text(city_time.daylight_label());
+            }
+        }
+
+        /// Démarre un nouveau minuteur.
+        ///
+        /// # Parameters
+        ///
+        /// * `name` - Nom du minuteur.
+        /// * `seconds` - Durée du compte à rebours, en secondes.
+        fn start_timer(&self, name: String, seconds: i64) {
+            if seconds <= 0 {
+                warn!(name = %name, "Timer without duration");
+                return;
+            }
+            let now = self.clock.now();
+            self.store.update_timers(|timers: &mut Vec<Timer>| {
+                let timer_id = timer::next_timer_id(timers);
+                info!(timer_id, name = %name, seconds, "Timer started");
+                timers.push(Timer::start(timer_id, name, seconds, now));
+            });
+        }
+
+        /// Démarre le minuteur décrit par le formulaire des minuteurs.
+        fn on_timer_start_clicked(&self) {
+            let name = self.widgets.i_timer_name.text().to_string();
+            let name = if name.is_empty() { "Minuteur".to_string() } else { name };
+            let seconds = self.widgets.s_timer_min.value_as_int() as i64 * 60 + self.widgets.s_timer_sec.value_as_int() as i64;
+            self.start_timer(name, seconds);
+            self.widgets.i_timer_name.set_text("");
+        }
+
+        /// Met en pause le minuteur en marche, relance le minuteur en pause.
+        ///
+        /// # Parameters
+        ///
+        /// * `timer_id` - Identifiant du minuteur.
+        fn on_timer_toggle_clicked(&self, timer_id: usize) {
+            let now = self.clock.now();
+            self.store.update_timers(|timers: &mut Vec<Timer>| {
+                if let Some(timer) = timers.iter_mut().find(|timer: &&mut Timer| timer.id == timer_id) {
+                    match timer.state {
+                        TimerState::Running { .. } => timer.pause(now),
+                        _ => timer.resume(now),
+                    }
+                }
+            });
+        }
+
+        /// Rétablit la durée initiale d'un minuteur, ou le supprime.
+        ///
+        /// La sonnerie est arrêtée si plus aucun minuteur ni aucune alarme ne sonne.
+        ///
+        /// # Parameters
+        ///
+        /// * `timer_id` - Identifiant du minuteur.
+        /// * `delete` - `true` pour supprimer le minuteur.
+        fn on_timer_reset_clicked(&self, timer_id: usize, delete: bool) {
+            let still_ringing = self.store.update_timers(|timers: &mut Vec<Timer>| {
+                if delete {
+                    timers.retain(|timer: &Timer| timer.id != timer_id);
+                } else if let Some(timer) = timers.iter_mut().find(|timer: &&mut Timer| timer.id == timer_id) {
+                    timer.reset();
+                }
+                timers.iter().any(Timer::is_ringing)
+            });
+            if !still_ringing && self.scheduler.lock().unwrap().ringing().is_none() && self.store.player().playing {
+                self.stop_player();
+            }
+        }
+
+        /// Fait sonner les minuteurs arrivés à zéro.
+        ///
+        /// La sonnerie passe par les lecteurs des alarmes ; elle n'interrompt pas une lecture en cours.
+        fn check_timers(&self) {
+            let now = self.clock.now();
+            if !self.store.timers().iter().any(|timer: &Timer| timer.is_due(now)) {
+                return;
+            }
+            let finished = self.store.update_timers(|timers: &mut Vec<Timer>| {
+                let mut finished = Vec::new();
+                for timer in timers.iter_mut().filter(|timer: &&mut Timer| timer.is_due(now)) {
+                    timer.ring(now);
+                    finished.push(timer.clone());
+                }
+                finished
+            });
+            for timer in finished {
+                info!(timer_id = timer.id, name = %timer.name, "Timer finished");
+                self.emit(Event::TimerFinished { timer_id: timer.id, name: timer.name });
+            }
+            if self.store.player().playing {
+                return;
+            }
+            let started = match &self.timer_sound {
+                Some(path) => self.start_player(false, path.clone()),
+                None => self.start_player(true, "".to_string()),
+            };
+            if let Err(error) = started {
+                self.report(&error);
+            }
+        }
+
+        /// Met à jour l'affichage des minuteurs.
+        fn update_timers_display(&self) {
+            self.widgets.timers_container.foreach(|child: &gtk::Widget| self.widgets.timers_container.remove(child));
+            let mut labels = self.timer_labels.borrow_mut();
+            labels.clear();
+            let now = self.clock.now();
+            for timer in self.store.timers().iter() {
+                let hbox_timer = Box::new(Orientation::Horizontal, 5);
+                let name_label = Label::new(Some(&timer.name));
+                name_label.set_xalign(0.0);
+                let remaining_label = Label::new(Some(&timer.remaining_label(now)));
+                remaining_label.set_widget_name("label-large");
+
+                let toggle_button = Button::with_label(match timer.state {
+                    TimerState::Running { .. } => "Pause",
+                    TimerState::Paused { .. } => "Reprendre",
+                    TimerState::Ringing { .. } => "Relancer",
+                });
+                let reset_button = Button::with_label("Réinitialiser");
+                let delete_button = Button::with_label("Supprimer");
+                let timer_id = timer.id;
+
+                let view = self.clone();
+                toggle_button.connect_clicked(move |_| {
+                    if view.store.timers().iter().any(|timer: &Timer| timer.id == timer_id && timer.is_ringing()) {
+                        // Relance le minuteur qui sonne pour sa durée initiale.
+                        view.on_timer_reset_clicked(timer_id, false);
+                    }
+                    view.on_timer_toggle_clicked(timer_id);
+                });
+
+                let view = self.clone();
+                reset_button.connect_clicked(move |_| view.on_timer_reset_clicked(timer_id, false));
+
+                let view = self.clone();
+                delete_button.connect_clicked(move |_| view.on_timer_reset_clicked(timer_id, true));
+
+                hbox_timer.pack_start(&name_label, true, true, 0);
+                hbox_timer.pack_start(&remaining_label, true, true, 0);
+                hbox_timer.pack_start(&toggle_button, false, false, 0);
+                hbox_timer.pack_start(&reset_button, false, false, 0);
+                hbox_timer.pack_start(&delete_button, false, false, 0);
+                self.widgets.timers_container.add(&hbox_timer);
+                labels.push((timer_id, remaining_label));
+            }
+
+            self.widgets.timers_container.show_all();
+        }
+
+        /// Met à jour le temps restant affiché pour chaque minuteur.
+        fn update_timer_labels(&self) {
+            let now = self.clock.now();
+            let timers = self.store.timers();
+            for (timer_id, label) in self.timer_labels.borrow().iter() {
+                if let Some(timer) = timers.iter().find(|timer: &&Timer| timer.id == *timer_id) {
+                    label.set_text(&timer.remaining_label(now));
+                }
+            }
+        }
+
         /// Sauvegarde une nouvelle alarme.
-        fn on_save_clicked(&mut self) {
+        fn on_save_clicked(&self) {
             self.add_alarms();
-            self.save_alarms().expect("Failed to save alarms");
-            self.update_alarms_display();
             self.widgets.g_alarm_clock.hide();
         }
 
@@ -506,18 +928,19 @@ pub mod view {
         /// # Parameters
         ///
         /// * `id_radio` - Identifiant de la station de radio sélectionnée.
-        pub fn on_radio_clicked(&mut self, id_radio: u8) {
+        pub fn on_radio_clicked(&self, id_radio: u8) {
             match id_radio {
-                1 => self.current_radio.lock().unwrap().selected_radio = Some(RadioStation::FranceInfo),
-                2 => self.current_radio.lock().unwrap().selected_radio = Some(RadioStation::FranceInter),
-                3 => self.current_radio.lock().unwrap().selected_radio = Some(RadioStation::RTL),
-                4 => self.current_radio.lock().unwrap().selected_radio = Some(RadioStation::RireChanson),
-                5 => self.current_radio.lock().unwrap().selected_radio = Some(RadioStation::Skyrock),
-                _ => println!("Radio button {} clicked", id_radio),
+                1 => self.store.select_radio(Some(RadioStation::FranceInfo)),
+                2 => self.store.select_radio(Some(RadioStation::FranceInter)),
+                3 => self.store.select_radio(Some(RadioStation::RTL)),
+                4 => self.store.select_radio(Some(RadioStation::RireChanson)),
+                5 => self.store.select_radio(Some(RadioStation::Skyrock)),
+                _ => debug!(id_radio, "Radio button clicked"),
             };
-            println!("[INFO] Radio button {} radio status {}", id_radio, self.player_status);
-            if self.player_status {
-                self.on_arret_clicked();
+            let playing = self.store.player().playing;
+            info!(id_radio, playing, "Radio button toggled");
+            if playing {
+                self.stop_player();
                 thread::sleep(time::Duration::from_millis(10));
                 self.on_marche_clicked();
             }
@@ -527,14 +950,15 @@ pub mod view {
         unsafe fn update_time_labels(&self) {
             let horaire_rc = self.horaire.clone();
             let sender = self.sender.clone();
+            let clock = self.clock.clone();
 
-            timeout_add_seconds(1, move || {
+            timeout_add(clock.check_interval(), move || {
                 let mut horaire = horaire_rc.lock().unwrap();
-                horaire.update_time();
+                horaire.update_time(clock.now());
 
                 // Envoyer un signal pour mettre à jour les widgets
                 if let Err(e) = sender.try_send(()) {
-                    eprintln!("[ERROR] Failed to send update signal: {:?}", e);
+                    error!(error = ?e, "Failed to send update signal");
                 }
 
                 ControlFlow::Continue
@@ -546,14 +970,16 @@ pub mod view {
         /// # Parameters
         ///
         /// * `receiver` - Récepteur de messages pour les mises à jour.
-        fn connect_receiver(&mut self, receiver: Receiver<()>) {
+        fn connect_receiver(&self, receiver: Receiver<()>) {
             let widgets_rc = self.widgets.clone();
             let horaire_rc = self.horaire.clone();
-            let view_rc = Arc::new(Mutex::new(self.clone()));
+            let view = self.clone();
 
             MainContext::default().spawn_local(async move {
                 while let Ok(_) = receiver.recv().await {
-                    view_rc.lock().unwrap().check_alarms();
+                    view.check_alarms();
+                    view.update_world_clock();
+                    view.update_timer_labels();
                     let horaire = horaire_rc.lock().unwrap();
                     widgets_rc.p_lcd_heure.set_text(&format!("{:02}", horaire.get_hour()));
                     widgets_rc.p_lcd_min.set_text(&format!("{:02}", horaire.get_min()));
@@ -561,5 +987,86 @@ pub mod view {
                 }
             });
         }
+
+        /// Abonne l'affichage et le planificateur aux changements du `Store`.
+        ///
+        /// L'état du lecteur est aussi diffusé aux clients sous la forme de `Event::PlayerState`.
+        fn connect_store(&self) {
+            let view = self.clone();
+            let mut changes = self.store.subscribe();
+
+            MainContext::default().spawn_local(async move {
+                loop {
+                    match changes.recv().await {
+                        Ok(Change::Alarms) | Ok(Change::AlarmsReloaded) | Err(broadcast::error::RecvError::Lagged(_)) => {
+                            view.scheduler.lock().unwrap().set_alarms(view.store.alarms());
+                            view.update_alarms_display();
+                        }
+                        Ok(Change::Player) => {
+                            let player = view.store.player();
+                            view.emit(Event::PlayerState { playing: player.playing, source: player.source });
+                        }
+                        Ok(Change::Timers) => view.update_timers_display(),
+                        Ok(Change::Radio) => {}
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+            });
+        }
+
+        /// Affiche l'état du système diffusé par les services (sortie de veille programmée, mise en veille bloquée)
+        /// et les erreurs signalées par l'application.
+        pub fn connect_events(&self) {
+            let widgets_rc = self.widgets.clone();
+            let mut events = self.events.subscribe();
+
+            MainContext::default().spawn_local(async move {
+                loop {
+                    match events.recv().await {
+                        Ok(Event::WakeArmed { at }) => {
+                            let text = match at {
+                                Some(at) => format!("Sortie de veille programmée : {}", at.format("%a %H:%M")),
+                                None => "Sortie de veille : non programmée".to_string(),
+                            };
+                            widgets_rc.p_wake_label.set_text(&text);
+                        }
+                        Ok(Event::SleepInhibited { reason }) => {
+                            let text = match reason {
+                                Some(reason) => format!("Mise en veille bloquée : {}", reason),
+                                None => "Mise en veille : autorisée".to_string(),
+                            };
+                            widgets_rc.p_inhibit_label.set_text(&text);
+                        }
+                        Ok(Event::Error { message }) => widgets_rc.p_error_label.set_text(&message),
+                        Ok(Event::StreamFailed { source, error }) => {
+                            widgets_rc.p_error_label.set_text(&format!("Échec de lecture de {} : {}", source, error));
+                        }
+                        Ok(Event::DownloadFailed { alarm }) => {
+                            widgets_rc.p_error_label.set_text(&format!("Échec du téléchargement de la chanson de l'alarme {}", alarm.alarm_id));
+                        }
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    }
+                }
+            });
+        }
+
+        /// Connecte le récepteur des commandes de la socket de contrôle.
+        ///
+        /// Les commandes sont exécutées dans la boucle GTK, comme les clics sur les boutons.
+        ///
+        /// # Parameters
+        ///
+        /// * `receiver` - Récepteur des commandes et de leur canal de réponse.
+        pub fn connect_control(&self, receiver: Receiver<ControlRequest>) {
+            let view = self.clone();
+
+            MainContext::default().spawn_local(async move {
+                while let Ok(request) = receiver.recv().await {
+                    let reply = view.execute(request.command);
+                    let _ = request.reply.send(reply);
+                }
+            });
+        }
     }
 }
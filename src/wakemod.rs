@@ -0,0 +1,414 @@
+pub mod wake {
+    use chrono::{DateTime, Duration as ChronoDuration, Local, Utc};
+    use futures::StreamExt;
+    use std::fs;
+    use std::io;
+    use std::path::PathBuf;
+    use std::process::Command as Process;
+    use std::sync::{Arc, Mutex};
+    use tokio::sync::broadcast::{self, error::RecvError};
+    use tokio::time::{interval, Duration};
+    use tracing::{error, info, warn};
+    use zbus::zvariant::OwnedFd;
+    use zbus::Connection;
+    use crate::configmod::config::{WakeBackendKind, WakeConfig};
+    use crate::controlmod::control::{self, Command, ControlRequest, Event};
+    use crate::logindmod::logind::LoginManagerProxy;
+    use crate::modelmod::model::{self, AlarmClock};
+
+    const RTC_WAKEALARM_PATH: &str = "/sys/class/rtc/rtc0/wakealarm";
+    const SYSTEMD_UNIT: &str = "alarm-clock-wake";
+
+    /// Intervalle de recalcul du réveil programmé, en secondes (le déclenchement passé d'une alarme décale le suivant).
+    const REFRESH_SECS: u64 = 60;
+
+    /// Mécanisme capable de sortir la machine de veille à une date donnée.
+    pub trait WakeBackend: Send {
+        /// Programme le réveil de la machine, en remplaçant le précédent.
+        ///
+        /// # Parameters
+        ///
+        /// * `at`: La date du réveil.
+        fn arm(&mut self, at: DateTime<Local>) -> io::Result<()>;
+
+        /// Annule le réveil programmé.
+        fn disarm(&mut self) -> io::Result<()>;
+    }
+
+    /// Réveil par l'horloge matérielle, via l'interface sysfs `wakealarm`.
+    pub struct RtcBackend {
+        path: PathBuf,
+    }
+
+    impl RtcBackend {
+        /// Crée une nouvelle instance de `RtcBackend` sur la première horloge matérielle.
+        pub fn new() -> Self {
+            RtcBackend {
+                path: PathBuf::from(RTC_WAKEALARM_PATH),
+            }
+        }
+    }
+
+    impl WakeBackend for RtcBackend {
+        fn arm(&mut self, at: DateTime<Local>) -> io::Result<()> {
+            // Le noyau refuse une nouvelle date tant que l'ancienne n'est pas effacée.
+            fs::write(&self.path, "0")?;
+            fs::write(&self.path, at.timestamp().to_string())
+        }
+
+        fn disarm(&mut self) -> io::Result<()> {
+            fs::write(&self.path, "0")
+        }
+    }
+
+    /// Réveil par un minuteur systemd transitoire avec `WakeSystem=yes`.
+    pub struct SystemdBackend;
+
+    impl SystemdBackend {
+        /// Exécute une commande et transforme un code de sortie non nul en erreur.
+        fn run(program: &str, args: &[&str]) -> io::Result<()> {
+            let output = Process::new(program).args(args).output()?;
+            if output.status.success() {
+                Ok(())
+            } else {
+                Err(io::Error::other(String::from_utf8_lossy(&output.stderr).trim().to_string()))
+            }
+        }
+    }
+
+    impl WakeBackend for SystemdBackend {
+        fn arm(&mut self, at: DateTime<Local>) -> io::Result<()> {
+            let _ = self.disarm();
+            let calendar = at.with_timezone(&Utc).format("%Y-%m-%d %H:%M:%S UTC").to_string();
+            Self::run("systemd-run", &[
+                &format!("--unit={}", SYSTEMD_UNIT),
+                &format!("--on-calendar={}", calendar),
+                "--timer-property=WakeSystem=yes",
+                "--timer-property=AccuracySec=1s",
+                "true",
+            ])
+        }
+
+        fn disarm(&mut self) -> io::Result<()> {
+            Self::run("systemctl", &["stop", &format!("{}.timer", SYSTEMD_UNIT)])
+        }
+    }
+
+    /// Faux réveil : enregistre les dates programmées sans toucher à la machine.
+    #[derive(Clone, Default)]
+    pub struct FakeBackend {
+        calls: Arc<Mutex<Vec<Option<DateTime<Local>>>>>,
+    }
+
+    impl FakeBackend {
+        /// Retourne les programmations reçues, `None` pour une annulation.
+        #[cfg(test)]
+        pub fn calls(&self) -> Vec<Option<DateTime<Local>>> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    impl WakeBackend for FakeBackend {
+        fn arm(&mut self, at: DateTime<Local>) -> io::Result<()> {
+            info!(%at, "Fake wake-up armed");
+            self.calls.lock().unwrap().push(Some(at));
+            Ok(())
+        }
+
+        fn disarm(&mut self) -> io::Result<()> {
+            info!("Fake wake-up disarmed");
+            self.calls.lock().unwrap().push(None);
+            Ok(())
+        }
+    }
+
+    /// Programme le réveil de la machine pour la prochaine alarme.
+    pub struct Waker {
+        backend: Box<dyn WakeBackend>,
+        lead: ChronoDuration,
+        armed: Option<DateTime<Local>>,
+        /// Le mécanisme a refusé l'accès : le réveil n'est plus retenté.
+        denied: bool,
+    }
+
+    impl Waker {
+        /// Crée une nouvelle instance de `Waker`.
+        ///
+        /// # Parameters
+        ///
+        /// * `backend`: Le mécanisme de réveil.
+        /// * `lead_secs`: L'avance du réveil sur l'alarme, en secondes.
+        ///
+        /// # Returns
+        ///
+        /// Une nouvelle instance de `Waker`, sans réveil programmé.
+        pub fn new(backend: Box<dyn WakeBackend>, lead_secs: u32) -> Self {
+            Waker {
+                backend,
+                lead: ChronoDuration::seconds(lead_secs as i64),
+                armed: None,
+                denied: false,
+            }
+        }
+
+        /// Retourne la date du réveil programmé.
+        pub fn armed(&self) -> Option<DateTime<Local>> {
+            self.armed
+        }
+
+        /// Programme le réveil pour la prochaine alarme active, ou l'annule s'il n'y en a plus.
+        ///
+        /// Une alarme plus proche que l'avance configurée réveille la machine à son heure exacte.
+        /// Après un refus d'accès, le réveil n'est plus programmé : les droits ne changent pas d'un recalcul à l'autre.
+        ///
+        /// # Parameters
+        ///
+        /// * `alarms`: Les alarmes.
+        /// * `now`: L'instant présent.
+        ///
+        /// # Returns
+        ///
+        /// `true` si le réveil programmé a changé.
+        pub fn update(&mut self, alarms: &[AlarmClock], now: DateTime<Local>) -> io::Result<bool> {
+            if self.denied {
+                return Ok(false);
+            }
+            let target = model::next_alarm(alarms, now).map(|(_, at)| {
+                if at - self.lead > now {
+                    at - self.lead
+                } else {
+                    at
+                }
+            });
+            if target == self.armed {
+                return Ok(false);
+            }
+            let programmed = match target {
+                Some(at) => self.backend.arm(at),
+                None => self.backend.disarm(),
+            };
+            if let Err(error) = programmed {
+                self.denied = error.kind() == io::ErrorKind::PermissionDenied;
+                return Err(error);
+            }
+            self.armed = target;
+            Ok(true)
+        }
+    }
+
+    /// Crée le mécanisme de réveil choisi dans la configuration.
+    fn backend(kind: WakeBackendKind) -> Box<dyn WakeBackend> {
+        match kind {
+            WakeBackendKind::Rtc => Box::new(RtcBackend::new()),
+            WakeBackendKind::Systemd => Box::new(SystemdBackend),
+            WakeBackendKind::Fake => Box::new(FakeBackend::default()),
+        }
+    }
+
+    /// Programme en tâche de fond le réveil de la machine avant chaque alarme, si la configuration l'active.
+    ///
+    /// Le réveil est recalculé quand les alarmes changent, régulièrement, et juste avant
+    /// chaque mise en veille annoncée par systemd-logind.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - Configuration du réveil.
+    /// * `requests` - Canal des commandes exécutées par l'application, pour lire les alarmes.
+    /// * `events` - Diffuseur des événements de l'application.
+    pub fn spawn(config: WakeConfig, requests: async_channel::Sender<ControlRequest>, events: &broadcast::Sender<Event>) {
+        if !config.enabled {
+            return;
+        }
+        let waker = Waker::new(backend(config.backend), config.lead_secs);
+        let events = events.clone();
+        tokio::spawn(run(waker, requests, events));
+    }
+
+    /// Prend le verrou qui retarde la mise en veille le temps de programmer le réveil.
+    async fn delay_lock(logind: &LoginManagerProxy<'_>) -> Option<OwnedFd> {
+        match logind.inhibit("sleep", "Alarm Clock", "Programmation du réveil avant la prochaine alarme", "delay").await {
+            Ok(fd) => Some(fd),
+            Err(error) => {
+                error!(?error, "Failed to take sleep delay lock");
+                None
+            }
+        }
+    }
+
+    /// Recalcule le réveil et signale son changement.
+    async fn refresh(waker: &mut Waker, requests: &async_channel::Sender<ControlRequest>, events: &broadcast::Sender<Event>) {
+        let alarms = match control::dispatch(requests, Command::ListAlarms).await {
+            Ok(alarms) => serde_json::from_value::<Vec<AlarmClock>>(alarms).unwrap_or_default(),
+            Err(error) => {
+                error!(%error, "Wake-up failed to list alarms");
+                return;
+            }
+        };
+        match waker.update(&alarms, Local::now()) {
+            Ok(true) => {
+                match waker.armed() {
+                    Some(at) => info!(%at, "Wake-up armed"),
+                    None => info!("Wake-up disarmed"),
+                }
+                let _ = events.send(Event::WakeArmed { at: waker.armed() });
+            }
+            Ok(false) => {}
+            Err(error) if error.kind() == io::ErrorKind::PermissionDenied => {
+                error!(?error, "Wake-up not permitted, giving up until restart");
+                let _ = events.send(Event::WakeArmed { at: None });
+                let _ = events.send(Event::Error { message: format!("Sortie de veille non autorisée : {}", error) });
+            }
+            Err(error) => {
+                error!(?error, "Failed to program wake-up");
+                let _ = events.send(Event::WakeArmed { at: None });
+            }
+        }
+    }
+
+    /// Boucle de programmation du réveil.
+    async fn run(mut waker: Waker, requests: async_channel::Sender<ControlRequest>, events: broadcast::Sender<Event>) {
+        let mut receiver = events.subscribe();
+        // Sans bus système (conteneur, tests), le réveil est tout de même programmé à chaque changement.
+        let logind = match Connection::system().await {
+            Ok(connection) => LoginManagerProxy::new(&connection).await.ok(),
+            Err(error) => {
+                warn!(?error, "System bus unavailable, no sleep notifications");
+                None
+            }
+        };
+        let mut sleep_signals = match &logind {
+            Some(logind) => logind.receive_prepare_for_sleep().await.ok(),
+            None => None,
+        };
+        let mut lock = match &logind {
+            Some(logind) => delay_lock(logind).await,
+            None => None,
+        };
+        let mut ticks = interval(Duration::from_secs(REFRESH_SECS));
+
+        loop {
+            tokio::select! {
+                event = receiver.recv() => match event {
+                    Ok(Event::AlarmsChanged) | Ok(Event::AlarmFired { .. }) | Ok(Event::AlarmDismissed { .. }) | Ok(Event::AlarmTimedOut { .. }) => {
+                        refresh(&mut waker, &requests, &events).await;
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                },
+                _ = ticks.tick() => refresh(&mut waker, &requests, &events).await,
+                Some(signal) = async { sleep_signals.as_mut()?.next().await } => {
+                    let Ok(args) = signal.args() else {
+                        continue;
+                    };
+                    if *args.start() {
+                        refresh(&mut waker, &requests, &events).await;
+                        // Le réveil est programmé : la mise en veille peut continuer.
+                        lock = None;
+                    } else if let Some(logind) = &logind {
+                        lock = delay_lock(logind).await;
+                        refresh(&mut waker, &requests, &events).await;
+                    }
+                }
+            }
+        }
+        drop(lock);
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use chrono::Datelike;
+        use crate::testmod::fixtures::{alarm, at};
+
+        #[test]
+        fn arms_ahead_of_next_alarm() {
+            let fake = FakeBackend::default();
+            let mut waker = Waker::new(Box::new(fake.clone()), 120);
+
+            assert!(waker.update(&[alarm(7, 30), alarm(6, 45)], at(1, 0)).unwrap());
+
+            assert_eq!(waker.armed(), Some(at(6, 43)));
+            assert_eq!(fake.calls(), vec![Some(at(6, 43))]);
+        }
+
+        #[test]
+        fn keeps_wake_until_alarms_change() {
+            let fake = FakeBackend::default();
+            let mut waker = Waker::new(Box::new(fake.clone()), 60);
+            let alarms = vec![alarm(7, 0)];
+
+            waker.update(&alarms, at(1, 0)).unwrap();
+            assert!(!waker.update(&alarms, at(2, 0)).unwrap());
+
+            let mut moved = alarm(8, 0);
+            moved.a_id = 1;
+            assert!(waker.update(&[moved], at(2, 0)).unwrap());
+            assert_eq!(fake.calls(), vec![Some(at(6, 59)), Some(at(7, 59))]);
+        }
+
+        #[test]
+        fn imminent_alarm_wakes_at_its_time() {
+            let fake = FakeBackend::default();
+            let mut waker = Waker::new(Box::new(fake.clone()), 300);
+
+            waker.update(&[alarm(7, 0)], at(6, 58)).unwrap();
+
+            assert_eq!(waker.armed(), Some(at(7, 0)));
+        }
+
+        #[test]
+        fn disarms_without_active_alarm() {
+            let fake = FakeBackend::default();
+            let mut waker = Waker::new(Box::new(fake.clone()), 60);
+            let mut alarms = vec![alarm(7, 0)];
+            waker.update(&alarms, at(1, 0)).unwrap();
+
+            alarms[0].active = false;
+            assert!(waker.update(&alarms, at(1, 0)).unwrap());
+
+            assert_eq!(waker.armed(), None);
+            assert_eq!(fake.calls(), vec![Some(at(6, 59)), None]);
+        }
+
+        /// Mécanisme sans droits, comme `rtc` hors root : compte les tentatives.
+        struct DeniedBackend {
+            attempts: Arc<Mutex<u32>>,
+        }
+
+        impl WakeBackend for DeniedBackend {
+            fn arm(&mut self, _at: DateTime<Local>) -> io::Result<()> {
+                *self.attempts.lock().unwrap() += 1;
+                Err(io::Error::from(io::ErrorKind::PermissionDenied))
+            }
+
+            fn disarm(&mut self) -> io::Result<()> {
+                self.arm(Local::now())
+            }
+        }
+
+        #[test]
+        fn permission_denied_is_not_retried() {
+            let attempts = Arc::new(Mutex::new(0));
+            let mut waker = Waker::new(Box::new(DeniedBackend { attempts: attempts.clone() }), 60);
+
+            let error = waker.update(&[alarm(7, 0)], at(1, 0)).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+            assert!(!waker.update(&[alarm(7, 0)], at(1, 1)).unwrap());
+            assert!(!waker.update(&[alarm(8, 0)], at(1, 2)).unwrap());
+            assert_eq!(*attempts.lock().unwrap(), 1);
+            assert_eq!(waker.armed(), None);
+        }
+
+        #[test]
+        fn rearms_for_next_day_after_alarm() {
+            let fake = FakeBackend::default();
+            let mut waker = Waker::new(Box::new(fake.clone()), 60);
+
+            waker.update(&[alarm(7, 0)], at(7, 1)).unwrap();
+
+            let armed = waker.armed().unwrap();
+            assert_eq!(armed.day(), 20);
+            assert_eq!(armed.format("%H:%M").to_string(), "06:59");
+        }
+    }
+}
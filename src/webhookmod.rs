@@ -0,0 +1,298 @@
+pub mod webhook {
+    use chrono::Local;
+    use reqwest::Client;
+    use serde_json::{json, Value};
+    use tokio::sync::broadcast::{self, error::RecvError};
+    use tokio::time::{sleep, Duration};
+    use tracing::{error, info, warn};
+    use crate::configmod::config::WebhookConfig;
+    use crate::controlmod::control::{AlarmInfo, Event};
+
+    /// Délai avant la première nouvelle tentative, doublé à chaque échec, en millisecondes.
+    const RETRY_DELAY_MS: u64 = 500;
+
+    /// Délai maximal entre deux tentatives, en millisecondes.
+    const MAX_RETRY_DELAY_MS: u64 = 60_000;
+
+    /// Retourne le délai avant une nouvelle tentative : 0,5 s, 1 s, 2 s... plafonné à `MAX_RETRY_DELAY_MS`.
+    ///
+    /// # Parameters
+    ///
+    /// * `attempt` - Numéro de la nouvelle tentative, à partir de 1.
+    fn retry_delay(attempt: u32) -> Duration {
+        let factor = 1u64.checked_shl(attempt.saturating_sub(1)).unwrap_or(u64::MAX);
+        Duration::from_millis(RETRY_DELAY_MS.saturating_mul(factor).min(MAX_RETRY_DELAY_MS))
+    }
+
+    /// Retourne le nom de webhook d'un événement et l'alarme concernée.
+    fn event_name(event: &Event) -> Option<(&'static str, &AlarmInfo)> {
+        match event {
+            Event::AlarmFired { alarm } => Some(("triggered", alarm)),
+            Event::AlarmSnoozed { alarm, .. } => Some(("snoozed", alarm)),
+            Event::AlarmDismissed { alarm } => Some(("dismissed", alarm)),
+            Event::AlarmTimedOut { alarm } => Some(("timed_out", alarm)),
+            Event::AlarmMissed { alarm } => Some(("missed", alarm)),
+            Event::PlaybackFailed { alarm } | Event::DownloadFailed { alarm } => Some(("failed", alarm)),
+            _ => None,
+        }
+    }
+
+    /// Indique si un webhook doit recevoir un événement.
+    ///
+    /// # Parameters
+    ///
+    /// * `hook` - Configuration du webhook.
+    /// * `name` - Nom de l'événement (`triggered`, `snoozed`...).
+    pub fn accepts(hook: &WebhookConfig, name: &str) -> bool {
+        hook.events.is_empty() || hook.events.iter().any(|event: &String| event == name)
+    }
+
+    /// Construit le message JSON envoyé pour un événement d'alarme.
+    ///
+    /// # Parameters
+    ///
+    /// * `event` - Événement de l'application.
+    ///
+    /// # Returns
+    ///
+    /// Le nom de l'événement et le message, ou `None` si l'événement ne concerne ni une alarme ni la lecture.
+    pub fn payload(event: &Event) -> Option<(&'static str, Value)> {
+        // Un flux radio coupé n'est pas toujours celui d'une alarme : le message n'en décrit aucune.
+        if let Event::StreamFailed { source, error } = event {
+            return Some(("failed", json!({
+                "event": "failed",
+                "timestamp": Local::now(),
+                "failure": "stream",
+                "source": source,
+                "error": error,
+            })));
+        }
+        let (name, alarm) = event_name(event)?;
+        let mut payload = json!({
+            "event": name,
+            "timestamp": Local::now(),
+            "alarm_id": alarm.alarm_id,
+            "name": alarm.name,
+            "scheduled": alarm.scheduled,
+            "source": alarm.source,
+        });
+        match event {
+            Event::AlarmSnoozed { until, .. } => payload["until"] = json!(until),
+            Event::PlaybackFailed { .. } => payload["failure"] = json!("playback"),
+            Event::DownloadFailed { .. } => payload["failure"] = json!("download"),
+            _ => {}
+        }
+        Some((name, payload))
+    }
+
+    /// Démarre l'envoi des webhooks en tâche de fond.
+    ///
+    /// # Parameters
+    ///
+    /// * `hooks` - Webhooks configurés ; ceux sans URL sont ignorés.
+    /// * `events` - Diffuseur des événements de l'application.
+    pub fn spawn(hooks: Vec<WebhookConfig>, events: &broadcast::Sender<Event>) {
+        let hooks: Vec<WebhookConfig> = hooks.into_iter().filter(|hook: &WebhookConfig| !hook.url.is_empty()).collect();
+        if hooks.is_empty() {
+            return;
+        }
+        let mut receiver = events.subscribe();
+        let client = Client::new();
+        tokio::spawn(async move {
+            loop {
+                let event = match receiver.recv().await {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Webhooks skipped events");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                let Some((name, payload)) = payload(&event) else {
+                    continue;
+                };
+                for hook in hooks.iter().filter(|hook: &&WebhookConfig| accepts(hook, name)) {
+                    let client = client.clone();
+                    let hook = hook.clone();
+                    let payload = payload.clone();
+                    tokio::spawn(async move {
+                        if let Err(error) = deliver(&client, &hook, &payload).await {
+                            error!(url = %hook.url, %error, "Webhook failed");
+                        }
+                    });
+                }
+            }
+        });
+    }
+
+    /// Envoie un message à un webhook, avec nouvelles tentatives en cas d'échec.
+    ///
+    /// # Parameters
+    ///
+    /// * `client` - Client HTTP.
+    /// * `hook` - Configuration du webhook (URL, délai, nombre de tentatives).
+    /// * `payload` - Message JSON à envoyer.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` dès qu'une tentative reçoit une réponse 2xx, sinon la dernière erreur.
+    pub async fn deliver(client: &Client, hook: &WebhookConfig, payload: &Value) -> Result<(), String> {
+        let mut last_error = String::new();
+        for attempt in 0..=hook.retries {
+            if attempt > 0 {
+                sleep(retry_delay(attempt)).await;
+            }
+            let response = client.post(&hook.url)
+                .timeout(Duration::from_secs(hook.timeout_secs))
+                .json(payload)
+                .send()
+                .await;
+            match response {
+                Ok(response) if response.status().is_success() => {
+                    info!(url = %hook.url, "Webhook delivered");
+                    return Ok(());
+                }
+                Ok(response) => last_error = format!("HTTP {}", response.status()),
+                Err(error) => last_error = error.to_string(),
+            }
+        }
+        Err(format!("{} after {} attempts", last_error, hook.retries + 1))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+        use tokio::task::JoinHandle;
+
+        /// Serveur HTTP local qui répond avec les statuts donnés, dans l'ordre,
+        /// et retourne les corps JSON reçus.
+        async fn test_server(statuses: Vec<u16>) -> (String, JoinHandle<Vec<Value>>) {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/hook", listener.local_addr().unwrap());
+            let handle = tokio::spawn(async move {
+                let mut bodies = Vec::new();
+                for status in statuses {
+                    let (mut stream, _) = listener.accept().await.unwrap();
+                    let mut request: Vec<u8> = Vec::new();
+                    let mut buffer = [0u8; 4096];
+                    loop {
+                        let read = stream.read(&mut buffer).await.unwrap();
+                        request.extend_from_slice(&buffer[..read]);
+                        let text = String::from_utf8_lossy(&request).to_string();
+                        if let Some(end) = text.find("\r\n\r\n") {
+                            let length = text.lines()
+                                .find_map(|line: &str| line.to_lowercase().strip_prefix("content-length:").map(|value: &str| value.trim().parse::<usize>().unwrap()))
+                                .unwrap_or(0);
+                            if request.len() >= end + 4 + length {
+                                bodies.push(serde_json::from_slice(&request[end + 4..end + 4 + length]).unwrap());
+                                break;
+                            }
+                        }
+                        if read == 0 {
+                            break;
+                        }
+                    }
+                    let response = format!("HTTP/1.1 {} Test\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", status);
+                    stream.write_all(response.as_bytes()).await.unwrap();
+                }
+                bodies
+            });
+            (url, handle)
+        }
+
+        fn fired_event() -> Event {
+            Event::AlarmFired {
+                alarm: AlarmInfo {
+                    alarm_id: 3,
+                    name: "Travail".to_string(),
+                    scheduled: "07:30:00".to_string(),
+                    source: "France Inter".to_string(),
+                },
+            }
+        }
+
+        fn hook(url: String, retries: u32) -> WebhookConfig {
+            WebhookConfig {
+                url,
+                events: Vec::new(),
+                timeout_secs: 5,
+                retries,
+            }
+        }
+
+        #[tokio::test]
+        async fn delivers_alarm_payload() {
+            let (url, server) = test_server(vec![200]).await;
+            let (_, payload) = payload(&fired_event()).unwrap();
+
+            deliver(&Client::new(), &hook(url, 0), &payload).await.unwrap();
+
+            let bodies = server.await.unwrap();
+            assert_eq!(bodies.len(), 1);
+            assert_eq!(bodies[0]["event"], "triggered");
+            assert_eq!(bodies[0]["alarm_id"], 3);
+            assert_eq!(bodies[0]["scheduled"], "07:30:00");
+            assert_eq!(bodies[0]["source"], "France Inter");
+        }
+
+        #[tokio::test]
+        async fn retries_after_server_error() {
+            let (url, server) = test_server(vec![500, 200]).await;
+            let (_, payload) = payload(&fired_event()).unwrap();
+
+            deliver(&Client::new(), &hook(url, 2), &payload).await.unwrap();
+
+            assert_eq!(server.await.unwrap().len(), 2);
+        }
+
+        #[tokio::test]
+        async fn gives_up_after_retries() {
+            let (url, server) = test_server(vec![500, 503]).await;
+            let (_, payload) = payload(&fired_event()).unwrap();
+
+            let result = deliver(&Client::new(), &hook(url, 1), &payload).await;
+
+            assert!(result.is_err());
+            assert_eq!(server.await.unwrap().len(), 2);
+        }
+
+        #[test]
+        fn failures_are_sent_as_failed() {
+            let Event::AlarmFired { alarm } = fired_event() else { unreachable!() };
+
+            let (name, body) = payload(&Event::DownloadFailed { alarm: alarm.clone() }).unwrap();
+            assert_eq!((name, &body["failure"], &body["alarm_id"]), ("failed", &json!("download"), &json!(3)));
+
+            let (name, body) = payload(&Event::PlaybackFailed { alarm }).unwrap();
+            assert_eq!((name, &body["failure"]), ("failed", &json!("playback")));
+
+            let (name, body) = payload(&Event::StreamFailed { source: "RTL".to_string(), error: "timeout".to_string() }).unwrap();
+            assert_eq!((name, &body["failure"], &body["source"], &body["error"]), ("failed", &json!("stream"), &json!("RTL"), &json!("timeout")));
+            assert!(body.get("alarm_id").is_none());
+        }
+
+        #[test]
+        fn retry_delay_doubles_up_to_its_cap() {
+            assert_eq!(retry_delay(1), Duration::from_millis(500));
+            assert_eq!(retry_delay(2), Duration::from_secs(1));
+            assert_eq!(retry_delay(3), Duration::from_secs(2));
+            assert_eq!(retry_delay(8), Duration::from_secs(60));
+            assert_eq!(retry_delay(60), Duration::from_secs(60));
+            assert_eq!(retry_delay(u32::MAX), Duration::from_secs(60));
+        }
+
+        #[test]
+        fn filters_events_per_hook() {
+            let mut filtered = hook("http://localhost/hook".to_string(), 0);
+            filtered.events = vec!["dismissed".to_string()];
+
+            assert!(accepts(&hook("http://localhost/hook".to_string(), 0), "triggered"));
+            assert!(accepts(&filtered, "dismissed"));
+            assert!(!accepts(&filtered, "triggered"));
+            assert!(!accepts(&filtered, "timed_out"));
+            assert!(payload(&Event::AlarmsChanged).is_none());
+        }
+    }
+}
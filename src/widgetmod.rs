@@ -1,5 +1,25 @@
 pub mod ihm {
-    use gtk::{Box, Button, Entry, Frame, Label, Orientation, RadioButton, SpinButton, CheckButton};
+    use gtk::prelude::*;
+    use gtk::{Box, Button, ComboBoxText, Entry, Frame, Label, Orientation, RadioButton, SpinButton, CheckButton};
+    use crate::timermod::timer::Preset;
+    use crate::worldclockmod::worldclock::City;
+
+    /// Ligne de l'horloge mondiale : une ville, son heure et un bouton pour y programmer une alarme.
+    #[derive(Clone)]
+    pub struct WorldClockRow {
+        pub city: City,
+        pub p_time: Label,
+        pub p_day_offset: Label,
+        pub p_daylight: Label,
+        pub p_add_alarm: Button,
+    }
+
+    /// Bouton démarrant un minuteur prédéfini.
+    #[derive(Clone)]
+    pub struct PresetButton {
+        pub preset: Preset,
+        pub p_start: Button,
+    }
 
     /// Structure contenant tous les widgets de l'application.
     #[derive(Clone)]
@@ -11,11 +31,13 @@ pub mod ihm {
         pub s_sec_box: SpinButton,
         pub i_name_ac: Entry,
         pub i_song_link: Entry,
+        pub c_time_zone: ComboBoxText,
         pub p_cancel: Button,
         pub p_save: Button,
         pub p_button_marche: Button,
         pub p_button_arret: Button,
         pub p_button_add_alarm_clock: Button,
+        pub p_button_snooze: Button,
         pub p_rad_b1: RadioButton,
         pub p_rad_b2: RadioButton,
         pub p_rad_b3: RadioButton,
@@ -24,17 +46,39 @@ pub mod ihm {
         pub p_lcd_heure: Label,
         pub p_lcd_min: Label,
         pub p_lcd_sec: Label,
+        pub p_wake_label: Label,
+        pub p_inhibit_label: Label,
+        pub p_error_label: Label,
         pub alarms_container: Box,
         pub days_checkbuttons: Vec<CheckButton>, // Checkboxes for each day of the week
+        pub g_stats: Frame,
+        pub p_stats_label: Label,
+        pub p_stats_refresh: Button,
+        pub p_export_csv: Button,
+        pub p_export_json: Button,
+        pub g_world_clock: Frame,
+        pub world_clock_rows: Vec<WorldClockRow>,
+        pub g_timers: Frame,
+        pub timer_presets: Vec<PresetButton>,
+        pub i_timer_name: Entry,
+        pub s_timer_min: SpinButton,
+        pub s_timer_sec: SpinButton,
+        pub p_timer_start: Button,
+        pub timers_container: Box,
     }
 
     impl Widgets {
         /// Crée une nouvelle instance de `Widgets` et initialise tous les composants de l'interface utilisateur.
         ///
+        /// # Parameters
+        ///
+        /// * `cities`: Les villes de l'horloge mondiale.
+        /// * `presets`: Les minuteurs prédéfinis.
+        ///
         /// # Returns
         ///
         /// Une nouvelle instance de `Widgets` avec tous les composants initialisés.
-        pub fn new() -> Self {
+        pub fn new(cities: &[City], presets: &[Preset]) -> Self {
             let p_rad_b1 = RadioButton::with_label("France Info");
             let p_rad_b2 = RadioButton::with_label_from_widget(&p_rad_b1, "France Inter");
             let p_rad_b3 = RadioButton::with_label_from_widget(&p_rad_b1, "RTL");
@@ -51,6 +95,31 @@ pub mod ihm {
                 CheckButton::with_label("Dim"),
             ];
 
+            // Fuseau horaire de la nouvelle alarme : celui du système, ou celui d'une ville de l'horloge mondiale.
+            let c_time_zone = ComboBoxText::new();
+            c_time_zone.append(Some(""), "Heure locale");
+            for city in cities.iter() {
+                c_time_zone.append(Some(city.zone.name()), &format!("{} ({})", city.name, city.zone.name()));
+            }
+            c_time_zone.set_active_id(Some(""));
+
+            let world_clock_rows = cities.iter()
+                .map(|city: &City| WorldClockRow {
+                    city: city.clone(),
+                    p_time: Label::new(None),
+                    p_day_offset: Label::new(None),
+                    p_daylight: Label::new(None),
+                    p_add_alarm: Button::with_label("Réveil ici"),
+                })
+                .collect();
+
+            let timer_presets = presets.iter()
+                .map(|preset: &Preset| PresetButton {
+                    preset: preset.clone(),
+                    p_start: Button::with_label(&format!("{} ({} min)", preset.name, preset.seconds.div_ceil(60))),
+                })
+                .collect();
+
             Widgets {
                 g_alarm_clock: Frame::new(Some("Nouveau réveil")),
                 g_alarm_clock_tab: Frame::new(Some("Réveil")),
@@ -59,11 +128,13 @@ pub mod ihm {
                 s_sec_box: SpinButton::with_range(0.0, 59.0, 1.0),
                 i_name_ac: Entry::new(),
                 i_song_link: Entry::new(),
+                c_time_zone,
                 p_cancel: Button::with_label("Annuler"),
                 p_save: Button::with_label("Sauvegarder"),
                 p_button_marche: Button::with_label("Marche"),
                 p_button_arret: Button::with_label("Arrêt"),
                 p_button_add_alarm_clock: Button::with_label("Ajouter un réveil"),
+                p_button_snooze: Button::with_label("Répéter"),
                 p_rad_b1,
                 p_rad_b2,
                 p_rad_b3,
@@ -72,8 +143,25 @@ pub mod ihm {
                 p_lcd_heure: Label::new(Some("00")),
                 p_lcd_min: Label::new(Some("00")),
                 p_lcd_sec: Label::new(Some("00")),
+                p_wake_label: Label::new(Some("Sortie de veille : non programmée")),
+                p_inhibit_label: Label::new(Some("Mise en veille : autorisée")),
+                p_error_label: Label::new(None),
                 alarms_container: Box::new(Orientation::Vertical, 10),
                 days_checkbuttons,
+                g_stats: Frame::new(Some("Statistiques")),
+                p_stats_label: Label::new(None),
+                p_stats_refresh: Button::with_label("Actualiser"),
+                p_export_csv: Button::with_label("Exporter CSV"),
+                p_export_json: Button::with_label("Exporter JSON"),
+                g_world_clock: Frame::new(Some("Horloge mondiale")),
+                world_clock_rows,
+                g_timers: Frame::new(Some("Minuteurs")),
+                timer_presets,
+                i_timer_name: Entry::new(),
+                s_timer_min: SpinButton::with_range(0.0, 999.0, 1.0),
+                s_timer_sec: SpinButton::with_range(0.0, 59.0, 1.0),
+                p_timer_start: Button::with_label("Démarrer"),
+                timers_container: Box::new(Orientation::Vertical, 5),
             }
         }
     }